@@ -0,0 +1,77 @@
+// FilePath: benches/checksum.rs
+
+//! Criterion benchmarks for the Verhoeff checksum library
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use verhoeff::tables::{D_TABLE, INV_TABLE, P_TABLE};
+use verhoeff::{calculate_checksum, calculate_checksum_result, validate, validate_ascii_unchecked};
+
+fn benchmark_checksum(c: &mut Criterion) {
+    let long = "1234567890".repeat(1000);
+
+    c.bench_function("checksum_12_digits", |b| {
+        b.iter(|| calculate_checksum(black_box("123456789012")))
+    });
+    c.bench_function("checksum_10000_digits", |b| {
+        b.iter(|| calculate_checksum(black_box(&long)))
+    });
+}
+
+fn benchmark_validate(c: &mut Criterion) {
+    let long = format!("{}{}", "1234567890".repeat(1000), 0);
+
+    c.bench_function("validate_12_digits", |b| {
+        b.iter(|| validate(black_box("123456789010")))
+    });
+    c.bench_function("validate_10000_digits", |b| {
+        b.iter(|| validate(black_box(&long)))
+    });
+}
+
+/// Checksum over decoded chars, as the crate did before the byte fast path
+fn checksum_by_char(input: &str) -> Option<u8> {
+    let mut c = 0u8;
+    for (i, ch) in input.chars().rev().enumerate() {
+        let digit = ch.to_digit(10)? as usize;
+        c = D_TABLE[c as usize][P_TABLE[(i + 1) % 8][digit] as usize];
+    }
+    Some(INV_TABLE[c as usize])
+}
+
+fn benchmark_ascii_fast_path(c: &mut Criterion) {
+    let input = "1234567890".repeat(100);
+    assert_eq!(
+        checksum_by_char(&input),
+        calculate_checksum_result(&input).ok()
+    );
+
+    let mut group = c.benchmark_group("checksum_1000_digits");
+    group.bench_function("bytes", |b| {
+        b.iter(|| calculate_checksum_result(black_box(&input)))
+    });
+    group.bench_function("chars", |b| b.iter(|| checksum_by_char(black_box(&input))));
+    group.finish();
+}
+
+fn benchmark_unchecked(c: &mut Criterion) {
+    let input = format!("{}{}", "1234567890".repeat(100), 0);
+    assert!(validate_ascii_unchecked(input.as_bytes()));
+
+    let mut group = c.benchmark_group("validate_1001_digits");
+    group.bench_function("checked", |b| b.iter(|| validate(black_box(&input))));
+    group.bench_function("unchecked", |b| {
+        b.iter(|| validate_ascii_unchecked(black_box(input.as_bytes())))
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    benchmark_checksum,
+    benchmark_validate,
+    benchmark_ascii_fast_path,
+    benchmark_unchecked
+);
+criterion_main!(benches);
@@ -2,7 +2,9 @@
 
 //! Basic usage examples for the Verhoeff checksum library
 
-use verhoeff::{append_checksum, calculate_checksum, validate, validate_aadhaar};
+use verhoeff::{
+    append_checksum, append_checksum_result, calculate_checksum, validate, validate_aadhaar,
+};
 
 fn main() {
     println!("Verhoeff Checksum Examples\n");
@@ -44,6 +46,14 @@ fn main() {
         println!("   {id} -> {with_checksum}");
     }
 
+    // Untrusted input: surface the error instead of echoing the input
+    for id in ["12345", "12a45"] {
+        match append_checksum_result(id) {
+            Ok(with_checksum) => println!("   {id} -> {with_checksum}"),
+            Err(e) => println!("   {id} -> ✗ Error: {e}"),
+        }
+    }
+
     // Example 4: Aadhaar validation
     println!("\n4. Aadhaar Validation:");
 
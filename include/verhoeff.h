@@ -0,0 +1,37 @@
+/* FilePath: include/verhoeff.h */
+
+/*
+ * C declarations for the verhoeff crate's `ffi` feature.
+ *
+ * All input strings are NUL-terminated UTF-8; only the digits 0-9 are
+ * accepted.
+ */
+
+#ifndef VERHOEFF_H
+#define VERHOEFF_H
+
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Returns the check digit 0-9, or -1 on error. */
+int verhoeff_calculate(const char *input);
+
+/* Returns 1 if valid, 0 if the checksum is wrong, or -1 on error. */
+int verhoeff_validate(const char *input);
+
+/*
+ * Writes input plus its check digit and a NUL terminator to out_buf, which
+ * must hold at least strlen(input) + 2 bytes. Returns the written length
+ * excluding the terminator, -1 on invalid input, or -2 if out_buf is null
+ * or too small.
+ */
+int verhoeff_append(const char *input, char *out_buf, size_t out_len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* VERHOEFF_H */
@@ -0,0 +1,488 @@
+// FilePath: src/aadhaar.rs
+
+//! Aadhaar and Virtual ID helpers
+//!
+//! UIDAI identifiers carry a standard Verhoeff check digit in their last
+//! position. This module adds the length rules and display conventions on
+//! top of the core algorithm; it is enabled by the default `aadhaar`
+//! feature.
+
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
+#[cfg(feature = "alloc")]
+use crate::{group_digits, validate_checked};
+use crate::{IdSpec, Result, VerhoeffError};
+
+/// The 12-digit Aadhaar number scheme
+pub const AADHAAR: IdSpec = IdSpec::new("Aadhaar number", 12);
+
+/// The 16-digit Virtual ID scheme
+pub const VID: IdSpec = IdSpec::new("Virtual ID", 16);
+
+/// Validate an Aadhaar number (12-digit Indian government ID).
+///
+/// # Arguments
+///
+/// * `aadhaar` - A string containing 12 digits
+///
+/// # Returns
+///
+/// * `Ok(true)` - If the Aadhaar number is valid
+/// * `Ok(false)` - If the checksum is invalid
+/// * `Err(VerhoeffError::InvalidAadhaarLength)` - If the length is wrong
+/// * `Err(VerhoeffError)` - If the input contains non-digits
+///
+/// Equivalent to [`AADHAAR`]`.validate`, except that the length error is the
+/// dedicated `InvalidAadhaarLength` variant.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::validate_aadhaar;
+///
+/// match validate_aadhaar("123456789012") {
+///     Ok(valid) => println!("Valid: {}", valid),
+///     Err(e) => println!("Error: {}", e),
+/// }
+/// ```
+pub fn validate_aadhaar(aadhaar: &str) -> Result<bool> {
+    validate_id(aadhaar, &AADHAAR, VerhoeffError::InvalidAadhaarLength)
+}
+
+/// Validate an Aadhaar number, also enforcing UIDAI's leading-digit rule.
+///
+/// Aadhaar numbers are never issued starting with 0 or 1, so on top of the
+/// checks made by [`validate_aadhaar`] this rejects a number whose first
+/// digit is not between 2 and 9. The leading digit is checked before the
+/// checksum result is returned, so it is reported even when the checksum
+/// is also wrong. [`validate_aadhaar`] stays lenient for backward
+/// compatibility and for test numbers such as `123456789010`.
+///
+/// # Returns
+///
+/// * `Ok(true)` - If the Aadhaar number is valid
+/// * `Ok(false)` - If the checksum is invalid
+/// * `Err(VerhoeffError::InvalidAadhaarLeadingDigit)` - If the number
+///   starts with 0 or 1
+/// * `Err(VerhoeffError)` - If the length is wrong or the input contains
+///   non-digits, as for [`validate_aadhaar`]
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{validate_aadhaar, validate_aadhaar_strict, VerhoeffError};
+///
+/// assert_eq!(validate_aadhaar_strict("234567890124"), Ok(true));
+///
+/// assert_eq!(validate_aadhaar("123456789010"), Ok(true));
+/// assert_eq!(
+///     validate_aadhaar_strict("123456789010"),
+///     Err(VerhoeffError::InvalidAadhaarLeadingDigit(1))
+/// );
+/// ```
+pub fn validate_aadhaar_strict(aadhaar: &str) -> Result<bool> {
+    let valid = validate_aadhaar(aadhaar)?;
+
+    let first = aadhaar.as_bytes()[0] - b'0';
+    if first < 2 {
+        return Err(VerhoeffError::InvalidAadhaarLeadingDigit(first));
+    }
+    Ok(valid)
+}
+
+/// Validate a Virtual ID (16-digit UIDAI VID).
+///
+/// A VID carries a Verhoeff check digit over its first 15 digits, just like
+/// an Aadhaar number does over its first 11.
+///
+/// # Arguments
+///
+/// * `vid` - A string containing 16 digits
+///
+/// # Returns
+///
+/// * `Ok(true)` - If the VID is valid
+/// * `Ok(false)` - If the checksum is invalid
+/// * `Err(VerhoeffError::InvalidVidLength)` - If the length is wrong
+/// * `Err(VerhoeffError)` - If the input contains non-digits
+///
+/// Equivalent to [`VID`]`.validate`, except that the length error is the
+/// dedicated `InvalidVidLength` variant.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::validate_vid;
+///
+/// assert_eq!(validate_vid("1234567890123455"), Ok(true));
+/// assert_eq!(validate_vid("1234567890123456"), Ok(false));
+/// assert!(validate_vid("123456789012").is_err());
+/// ```
+pub fn validate_vid(vid: &str) -> Result<bool> {
+    validate_id(vid, &VID, VerhoeffError::InvalidVidLength)
+}
+
+/// [`IdSpec::validate`] with the length error replaced by the
+/// scheme-specific variant
+fn validate_id(
+    input: &str,
+    spec: &IdSpec,
+    length_error: fn(usize) -> VerhoeffError,
+) -> Result<bool> {
+    spec.validate(input).map_err(|e| match e {
+        VerhoeffError::InvalidIdLength { got, .. } => length_error(got),
+        e => e,
+    })
+}
+
+/// An identifier scheme, used to pick validation rules and display format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scheme {
+    /// 12-digit Aadhaar number, displayed as `XXXX XXXX XXXX`
+    Aadhaar,
+    /// 16-digit Virtual ID, displayed as `XXXX XXXX XXXX XXXX`
+    Vid,
+    /// Any number of digits ending in a Verhoeff check digit, displayed as is
+    Generic,
+}
+
+/// Validate a number per `scheme` and render it for display to a user.
+///
+/// This is the single "show this ID" function: it only ever returns a
+/// representation of a valid number. Aadhaar numbers and VIDs are grouped
+/// in blocks of four separated by spaces; generic numbers are returned
+/// unchanged.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The canonical display form
+/// * `Err(VerhoeffError)` - If the input is malformed for the scheme, or
+///   `ChecksumMismatch` if its check digit is wrong
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{to_display_string, Scheme};
+///
+/// assert_eq!(
+///     to_display_string("123456789010", Scheme::Aadhaar).unwrap(),
+///     "1234 5678 9010"
+/// );
+/// assert_eq!(to_display_string("123451", Scheme::Generic).unwrap(), "123451");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn to_display_string(input: &str, scheme: Scheme) -> Result<String> {
+    match scheme {
+        Scheme::Aadhaar => {
+            // Structural checks first, then the checksum with mismatch details
+            validate_aadhaar(input)?;
+            validate_checked(input)?;
+            Ok(group_digits(input, 4, ' '))
+        }
+        Scheme::Vid => {
+            validate_vid(input)?;
+            validate_checked(input)?;
+            Ok(group_digits(input, 4, ' '))
+        }
+        Scheme::Generic => {
+            validate_checked(input)?;
+            Ok(input.to_string())
+        }
+    }
+}
+
+/// Format an Aadhaar number as `XXXX XXXX XXXX`.
+///
+/// The input goes through the same structural checks as
+/// [`validate_aadhaar`], so it must be exactly 12 digits. The checksum
+/// itself is not enforced; use [`to_display_string`] for that.
+///
+/// # Arguments
+///
+/// * `aadhaar` - A string containing 12 digits
+///
+/// # Returns
+///
+/// * `Ok(String)` - The number in three space-separated blocks of four
+/// * `Err(VerhoeffError)` - If the length is wrong or a non-digit is present
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{format_aadhaar, validate_with_separators};
+///
+/// let formatted = format_aadhaar("123456789010").unwrap();
+/// assert_eq!(formatted, "1234 5678 9010");
+/// assert_eq!(validate_with_separators(&formatted, &[' ']), Ok(true));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn format_aadhaar(aadhaar: &str) -> Result<String> {
+    validate_aadhaar(aadhaar)?;
+    Ok(group_digits(aadhaar, 4, ' '))
+}
+
+/// Mask an Aadhaar number for logging, revealing only the last four digits.
+///
+/// Equivalent to [`mask_aadhaar_with`] using `'X'` as the mask character.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::mask_aadhaar;
+///
+/// assert_eq!(mask_aadhaar("123456789010").unwrap(), "XXXX XXXX 9010");
+/// assert!(mask_aadhaar("1234").is_err());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn mask_aadhaar(aadhaar: &str) -> Result<String> {
+    mask_aadhaar_with(aadhaar, 'X')
+}
+
+/// Mask an Aadhaar number with a custom mask character.
+///
+/// The number must pass the structural checks of [`validate_aadhaar`];
+/// malformed input is rejected rather than masked.
+///
+/// # Arguments
+///
+/// * `aadhaar` - A string containing 12 digits
+/// * `mask` - The character replacing each of the first eight digits
+///
+/// # Returns
+///
+/// * `Ok(String)` - The masked number in blocks of four, e.g. `**** **** 9010`
+/// * `Err(VerhoeffError)` - If the length is wrong or a non-digit is present
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::mask_aadhaar_with;
+///
+/// assert_eq!(mask_aadhaar_with("123456789010", '*').unwrap(), "**** **** 9010");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn mask_aadhaar_with(aadhaar: &str, mask: char) -> Result<String> {
+    validate_aadhaar(aadhaar)?;
+    let masked: String = core::iter::repeat(mask)
+        .take(8)
+        .chain(aadhaar[8..].chars())
+        .collect();
+    Ok(group_digits(&masked, 4, ' '))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculate_checksum;
+    #[cfg(feature = "alloc")]
+    use crate::validate_with_separators;
+
+    #[test]
+    fn test_validate_aadhaar() {
+        // Valid format but we'll test with a made-up number
+        // In real usage, you'd test with actual valid Aadhaar numbers
+        let test_number = "12345678901";
+        let checksum = calculate_checksum(test_number);
+        let full_number = format!("{test_number}{checksum}");
+
+        assert!(validate_aadhaar(&full_number).unwrap());
+
+        // Test invalid checksum
+        let invalid = format!("{test_number}9");
+        if checksum != 9 {
+            assert!(!validate_aadhaar(&invalid).unwrap());
+        }
+
+        // Test invalid length
+        assert!(validate_aadhaar("12345").is_err());
+        assert!(validate_aadhaar("1234567890123").is_err());
+
+        // Test non-digit characters
+        assert!(validate_aadhaar("12345678901a").is_err());
+    }
+
+    #[test]
+    fn test_validate_aadhaar_strict() {
+        for first in 2..=9 {
+            let base = format!("{first}3456789012");
+            let full = format!("{base}{}", calculate_checksum(&base));
+            assert_eq!(validate_aadhaar_strict(&full), Ok(true), "{full}");
+            assert_eq!(validate_aadhaar_strict(&full), validate_aadhaar(&full));
+        }
+        assert_eq!(validate_aadhaar_strict("234567890125"), Ok(false));
+
+        // Valid checksums, but numbers UIDAI never issues
+        for first in 0..=1 {
+            let base = format!("{first}3456789012");
+            let full = format!("{base}{}", calculate_checksum(&base));
+            assert_eq!(validate_aadhaar(&full), Ok(true), "{full}");
+            assert_eq!(
+                validate_aadhaar_strict(&full),
+                Err(VerhoeffError::InvalidAadhaarLeadingDigit(first))
+            );
+        }
+
+        // The leading digit is reported even if the checksum is also wrong
+        assert_eq!(
+            validate_aadhaar_strict("023456789010"),
+            Err(VerhoeffError::InvalidAadhaarLeadingDigit(0))
+        );
+    }
+
+    #[test]
+    fn test_validate_aadhaar_strict_structural_errors_first() {
+        assert_eq!(
+            validate_aadhaar_strict("12345"),
+            Err(VerhoeffError::InvalidAadhaarLength(5))
+        );
+        assert_eq!(
+            validate_aadhaar_strict("1a3456789010"),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 1 })
+        );
+        assert_eq!(validate_aadhaar_strict(""), validate_aadhaar(""));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_to_display_string() {
+        assert_eq!(
+            to_display_string("123456789010", Scheme::Aadhaar).unwrap(),
+            "1234 5678 9010"
+        );
+        assert_eq!(
+            to_display_string("123451", Scheme::Generic).unwrap(),
+            "123451"
+        );
+        assert_eq!(
+            to_display_string("1234567890123455", Scheme::Vid).unwrap(),
+            "1234 5678 9012 3455"
+        );
+        assert_eq!(
+            to_display_string("123456789010", Scheme::Generic).unwrap(),
+            "123456789010"
+        );
+
+        // Only valid numbers are ever rendered
+        assert_eq!(
+            to_display_string("123456789013", Scheme::Aadhaar),
+            Err(VerhoeffError::ChecksumMismatch {
+                expected: 0,
+                found: 3
+            })
+        );
+        assert_eq!(
+            to_display_string("123451", Scheme::Aadhaar),
+            Err(VerhoeffError::InvalidAadhaarLength(6))
+        );
+        assert_eq!(
+            to_display_string("123452", Scheme::Generic),
+            Err(VerhoeffError::ChecksumMismatch {
+                expected: 1,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_format_aadhaar() {
+        assert_eq!(format_aadhaar("123456789010").unwrap(), "1234 5678 9010");
+
+        // Leading zeros must survive grouping
+        let base = "00012345678";
+        let aadhaar = format!("{base}{}", calculate_checksum(base));
+        let formatted = format_aadhaar(&aadhaar).unwrap();
+        assert!(formatted.starts_with("0001 2345 678"));
+        assert_eq!(formatted.replace(' ', ""), aadhaar);
+        assert_eq!(validate_with_separators(&formatted, &[' ']), Ok(true));
+
+        assert_eq!(
+            format_aadhaar("12345678901"),
+            Err(VerhoeffError::InvalidAadhaarLength(11))
+        );
+        assert_eq!(
+            format_aadhaar("12345678901a"),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 11 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_mask_aadhaar() {
+        let masked = mask_aadhaar("123456789010").unwrap();
+        assert_eq!(masked, "XXXX XXXX 9010");
+
+        // Exactly the last four digits survive
+        let digits: String = masked.chars().filter(char::is_ascii_digit).collect();
+        assert_eq!(digits, "9010");
+
+        assert_eq!(
+            mask_aadhaar_with("000012345678", '#').unwrap(),
+            "#### #### 5678"
+        );
+
+        assert_eq!(
+            mask_aadhaar("1234567890"),
+            Err(VerhoeffError::InvalidAadhaarLength(10))
+        );
+        assert_eq!(
+            mask_aadhaar("1234 5678 90"),
+            Err(VerhoeffError::InvalidCharacter { ch: ' ', index: 4 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_validate_vid() {
+        let test_number = "987654321098765";
+        let checksum = calculate_checksum(test_number);
+        let full_number = format!("{test_number}{checksum}");
+
+        assert!(validate_vid(&full_number).unwrap());
+
+        // Test invalid checksum
+        let wrong = (checksum + 1) % 10;
+        let invalid = format!("{test_number}{wrong}");
+        assert!(!validate_vid(&invalid).unwrap());
+
+        // Test invalid length, including a valid Aadhaar-length number
+        assert_eq!(
+            validate_vid("123456789010"),
+            Err(VerhoeffError::InvalidVidLength(12))
+        );
+        assert_eq!(
+            validate_vid("12345678901234567"),
+            Err(VerhoeffError::InvalidVidLength(17))
+        );
+
+        // Test non-digit characters
+        assert_eq!(
+            validate_vid("123456789012345a"),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 15 })
+        );
+    }
+
+    #[test]
+    fn test_specs() {
+        assert_eq!(
+            AADHAAR.validate("123456789010"),
+            validate_aadhaar("123456789010")
+        );
+        assert_eq!(VID.validate("1234567890123455"), Ok(true));
+
+        let err = AADHAAR.validate("12345").unwrap_err();
+        assert_eq!(
+            err,
+            VerhoeffError::InvalidIdLength {
+                name: "Aadhaar number",
+                expected: 12,
+                got: 5
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "Aadhaar number must be 12 digits, got 5 digits"
+        );
+    }
+}
@@ -0,0 +1,884 @@
+// FilePath: src/analysis.rs
+
+//! Error analysis and correction helpers
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    accumulate, check_digits, validate, validate_checked, Result, VerhoeffError, D_TABLE,
+    INV_TABLE, P_TABLE,
+};
+
+/// The lookup tables driving a Verhoeff-style scheme
+struct Tables<'a> {
+    d: &'a [[u8; 10]; 10],
+    p: &'a [[u8; 10]; 8],
+    inv: &'a [u8; 10],
+}
+
+/// The canonical D₅ tables used by the rest of the crate
+const STANDARD: Tables<'static> = Tables {
+    d: &D_TABLE,
+    p: &P_TABLE,
+    inv: &INV_TABLE,
+};
+
+/// Compute the validation-loop factor of every digit, indexed by reverse
+/// position (rightmost digit first).
+fn factors(tables: &Tables, input: &str) -> Vec<u8> {
+    input
+        .bytes()
+        .rev()
+        .enumerate()
+        .map(|(i, b)| tables.p[i % 8][(b - b'0') as usize])
+        .collect()
+}
+
+/// Find every single-digit substitution that would make `input` validate.
+///
+/// Returns `(position, replacement)` pairs, where `position` is the
+/// zero-based index from the left and `replacement` differs from the digit
+/// currently there. Verhoeff detects every single-digit error but cannot
+/// uniquely correct one, so several candidates may be returned; the true
+/// correction of a single typo is always among them. Candidates are sorted
+/// by position, then replacement. Malformed input yields no candidates.
+///
+/// This runs in O(len * 10): the validation product is split around each
+/// position so every candidate is checked with a single table lookup.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::find_single_error_candidates;
+///
+/// // "123451" mistyped as "123491"
+/// let candidates = find_single_error_candidates("123491");
+/// assert!(candidates.contains(&(4, 5)));
+/// ```
+pub fn find_single_error_candidates(input: &str) -> Vec<(usize, u8)> {
+    if check_digits(input).is_err() {
+        return Vec::new();
+    }
+    single_error_candidates_in(&STANDARD, input)
+}
+
+/// [`find_single_error_candidates`] over arbitrary tables, for input that
+/// has already been checked; sorted by position, then replacement
+fn single_error_candidates_in(tables: &Tables, input: &str) -> Vec<(usize, u8)> {
+    let factors = factors(tables, input);
+    let n = factors.len();
+
+    // suffix[i] = factors[i] · factors[i + 1] · ... · factors[n - 1]
+    let mut suffix = alloc::vec![0u8; n + 1];
+    for i in (0..n).rev() {
+        suffix[i] = tables.d[factors[i] as usize][suffix[i + 1] as usize];
+    }
+
+    let mut candidates = Vec::new();
+    let mut prefix = 0u8;
+    let bytes = input.as_bytes();
+
+    for (i, &factor) in factors.iter().enumerate() {
+        let position = n - 1 - i;
+        let current = bytes[position] - b'0';
+
+        for replacement in 0..10u8 {
+            if replacement == current {
+                continue;
+            }
+            let permuted = tables.p[i % 8][replacement as usize];
+            let left = tables.d[prefix as usize][permuted as usize];
+            if tables.d[left as usize][suffix[i + 1] as usize] == 0 {
+                candidates.push((position, replacement));
+            }
+        }
+
+        prefix = tables.d[prefix as usize][factor as usize];
+    }
+
+    candidates.sort_unstable();
+    candidates
+}
+
+/// Find every single-digit substitution of a valid number that would go
+/// undetected.
+///
+/// Returns the `(position, replacement)` pairs for which the altered number
+/// still validates, with positions zero-based from the left. Verhoeff
+/// detects every single-digit substitution, so with the standard tables
+/// this is always empty; a non-empty result would mean the tables are
+/// broken. That makes it a strong invariant check to run over real data.
+///
+/// # Returns
+///
+/// * `Ok(Vec<(usize, u8)>)` - The undetectable substitutions, ascending
+/// * `Err(VerhoeffError::ChecksumMismatch)` - If `input` is not valid
+/// * `Err(VerhoeffError)` - If the input is empty or contains non-digits
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::undetectable_single_errors;
+///
+/// assert_eq!(undetectable_single_errors("123456789010"), Ok(vec![]));
+/// assert!(undetectable_single_errors("123456789013").is_err());
+/// ```
+pub fn undetectable_single_errors(input: &str) -> Result<Vec<(usize, u8)>> {
+    validate_checked(input)?;
+    Ok(single_error_candidates_in(&STANDARD, input))
+}
+
+/// Find an adjacent transposition of a valid number that would go
+/// undetected.
+///
+/// Returns the zero-based positions `(i, i + 1)` of the leftmost pair of
+/// differing adjacent digits whose swap still validates, or `None` if every
+/// such swap is caught. Verhoeff detects all adjacent transpositions, so
+/// with the standard tables this is always `None`; `Some` would flag a
+/// defect in the tables. Swapping two equal digits changes nothing and is
+/// never reported.
+///
+/// Only adjacent swaps are covered. Jump transpositions such as `abc` to
+/// `cba` are a separate class that Verhoeff usually, but not always,
+/// detects.
+///
+/// # Returns
+///
+/// * `Ok(Option<(usize, usize)>)` - The undetected pair, if any
+/// * `Err(VerhoeffError::ChecksumMismatch)` - If `input` is not valid
+/// * `Err(VerhoeffError)` - If the input is empty or contains non-digits
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::weakest_transposition;
+///
+/// assert_eq!(weakest_transposition("123456789010"), Ok(None));
+/// ```
+pub fn weakest_transposition(input: &str) -> Result<Option<(usize, usize)>> {
+    validate_checked(input)?;
+    Ok(weakest_transposition_in(&STANDARD, input))
+}
+
+/// [`weakest_transposition`] over arbitrary tables, for input that has
+/// already been checked
+fn weakest_transposition_in(tables: &Tables, input: &str) -> Option<(usize, usize)> {
+    let digits: Vec<u8> = input.bytes().rev().map(|b| b - b'0').collect();
+    let factors = factors(tables, input);
+    let n = factors.len();
+
+    let mut suffix = alloc::vec![0u8; n + 1];
+    for i in (0..n).rev() {
+        suffix[i] = tables.d[factors[i] as usize][suffix[i + 1] as usize];
+    }
+
+    // prefix[i] = factors[0] · ... · factors[i - 1]
+    let mut prefix = alloc::vec![0u8; n + 1];
+    for i in 0..n {
+        prefix[i + 1] = tables.d[prefix[i] as usize][factors[i] as usize];
+    }
+
+    // Reverse positions from the left end down, so the leftmost pair wins
+    (0..n.saturating_sub(1)).rev().find_map(|i| {
+        let (a, b) = (digits[i], digits[i + 1]);
+        if a == b {
+            return None;
+        }
+        let first = tables.p[i % 8][b as usize];
+        let second = tables.p[(i + 1) % 8][a as usize];
+        let left = tables.d[tables.d[prefix[i] as usize][first as usize] as usize][second as usize];
+        (tables.d[left as usize][suffix[i + 2] as usize] == 0).then_some((n - 2 - i, n - 1 - i))
+    })
+}
+
+/// A class of typing error for [`simulate_errors`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// One digit replaced by another, e.g. `123` to `183`
+    SingleSubstitution,
+    /// Two adjacent digits swapped, e.g. `123` to `213`
+    AdjacentTransposition,
+    /// Two digits one place apart swapped, e.g. `123` to `321`
+    JumpTransposition,
+    /// One digit left out, e.g. `123` to `13`
+    Omission,
+}
+
+impl ErrorKind {
+    /// Appends every distinct corruption of this kind of `digits` to `out`
+    fn corrupt(self, digits: &[u8], out: &mut Vec<Vec<u8>>) {
+        let n = digits.len();
+        match self {
+            ErrorKind::SingleSubstitution => {
+                for i in 0..n {
+                    for d in (b'0'..=b'9').filter(|&d| d != digits[i]) {
+                        let mut v = digits.to_vec();
+                        v[i] = d;
+                        out.push(v);
+                    }
+                }
+            }
+            ErrorKind::AdjacentTransposition | ErrorKind::JumpTransposition => {
+                let gap = if self == ErrorKind::AdjacentTransposition {
+                    1
+                } else {
+                    2
+                };
+                for i in 0..n.saturating_sub(gap) {
+                    if digits[i] != digits[i + gap] {
+                        let mut v = digits.to_vec();
+                        v.swap(i, i + gap);
+                        out.push(v);
+                    }
+                }
+            }
+            ErrorKind::Omission => {
+                for i in 0..n {
+                    // Dropping any digit of a run gives the same result
+                    if i == 0 || digits[i] != digits[i - 1] {
+                        let mut v = digits.to_vec();
+                        v.remove(i);
+                        out.push(v);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Inject typing errors into a valid number and report which are detected.
+///
+/// For each kind in `kinds`, every distinct corruption of `full` of that
+/// kind is generated, in order of position, and paired with whether
+/// [`validate`](crate::validate) rejects it. Verhoeff detects every
+/// single substitution and adjacent transposition, so those are always
+/// `true`; jump transpositions and omissions are usually, but not always,
+/// detected.
+///
+/// # Returns
+///
+/// * `Ok(Vec<(String, bool)>)` - Each corrupted number and whether it was
+///   detected
+/// * `Err(VerhoeffError::ChecksumMismatch)` - If `full` is not valid
+/// * `Err(VerhoeffError)` - If the input is empty or contains non-digits
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{simulate_errors, ErrorKind};
+///
+/// let results = simulate_errors("2363", &[ErrorKind::AdjacentTransposition])?;
+/// assert_eq!(
+///     results,
+///     vec![("3263".to_string(), true), ("2633".to_string(), true), ("2336".to_string(), true)]
+/// );
+/// # Ok::<(), verhoeff::VerhoeffError>(())
+/// ```
+pub fn simulate_errors(full: &str, kinds: &[ErrorKind]) -> Result<Vec<(String, bool)>> {
+    validate_checked(full)?;
+
+    let mut corrupted = Vec::new();
+    for kind in kinds {
+        kind.corrupt(full.as_bytes(), &mut corrupted);
+    }
+
+    Ok(corrupted
+        .into_iter()
+        .map(|bytes| {
+            let s = String::from_utf8(bytes).expect("digits are ASCII");
+            let detected = !validate(&s);
+            (s, detected)
+        })
+        .collect())
+}
+
+/// Find the digit positions of a base number that influence its check digit.
+///
+/// A position is *critical* when substituting some other digit there
+/// changes the computed check digit. Positions are zero-based from the left.
+/// For the standard Verhoeff tables every position is critical, because each
+/// permutation row and each row of the group table is a bijection; computing
+/// it from the actual tables verifies that sensitivity property rather than
+/// assuming it.
+///
+/// # Arguments
+///
+/// * `input` - The base number, without its check digit
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::critical_positions;
+///
+/// assert_eq!(critical_positions("12345").unwrap(), vec![0, 1, 2, 3, 4]);
+/// ```
+pub fn critical_positions(input: &str) -> Result<Vec<usize>> {
+    critical_positions_in(&STANDARD, input)
+}
+
+fn critical_positions_in(tables: &Tables, input: &str) -> Result<Vec<usize>> {
+    check_digits(input)?;
+
+    // Checksum-loop factors, rightmost digit first
+    let factors: Vec<u8> = input
+        .bytes()
+        .rev()
+        .enumerate()
+        .map(|(i, b)| tables.p[(i + 1) % 8][(b - b'0') as usize])
+        .collect();
+    let n = factors.len();
+
+    let mut suffix = alloc::vec![0u8; n + 1];
+    for i in (0..n).rev() {
+        suffix[i] = tables.d[factors[i] as usize][suffix[i + 1] as usize];
+    }
+
+    let mut critical = Vec::new();
+    let mut prefix = 0u8;
+
+    for (i, &factor) in factors.iter().enumerate() {
+        let check_with = |digit: u8| {
+            let permuted = tables.p[(i + 1) % 8][digit as usize];
+            let left = tables.d[prefix as usize][permuted as usize];
+            tables.inv[tables.d[left as usize][suffix[i + 1] as usize] as usize]
+        };
+
+        let first = check_with(0);
+        if (1..10).any(|digit| check_with(digit) != first) {
+            critical.push(n - 1 - i);
+        }
+
+        prefix = tables.d[prefix as usize][factor as usize];
+    }
+
+    critical.reverse();
+    Ok(critical)
+}
+
+/// Find every digit that can replace a single unknown digit so the full
+/// number validates.
+///
+/// `input_with_placeholder` is a full number, including its check digit,
+/// in which exactly one character is `placeholder`. Because each position
+/// applies a bijective permutation, the standard tables always give exactly
+/// one solution, but the result is a `Vec` so that callers need not rely on
+/// that.
+///
+/// # Returns
+///
+/// * `Ok(Vec<u8>)` - The digits that make the number validate, ascending
+/// * `Err(VerhoeffError)` - `InvalidPlaceholderCount` unless exactly one
+///   placeholder is present, or the usual errors for other non-digits
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::solve_unknown;
+///
+/// assert_eq!(solve_unknown("12?451", '?'), Ok(vec![3]));
+/// ```
+pub fn solve_unknown(input_with_placeholder: &str, placeholder: char) -> Result<Vec<u8>> {
+    if input_with_placeholder.is_empty() {
+        return Err(VerhoeffError::EmptyInput);
+    }
+
+    let mut placeholders = 0;
+    for (index, ch) in input_with_placeholder.chars().enumerate() {
+        if ch == placeholder {
+            placeholders += 1;
+        } else if !ch.is_ascii_digit() {
+            return Err(VerhoeffError::InvalidCharacter { ch, index });
+        }
+    }
+    if placeholders != 1 {
+        return Err(VerhoeffError::InvalidPlaceholderCount(placeholders));
+    }
+
+    let solutions = (0..10u8)
+        .filter(|&candidate| {
+            let digits = input_with_placeholder.chars().rev().map(|c| {
+                if c == placeholder {
+                    candidate
+                } else {
+                    c as u8 - b'0'
+                }
+            });
+            accumulate(digits, 0, 0) == 0
+        })
+        .collect();
+
+    Ok(solutions)
+}
+
+/// Correct a single-digit error, but only when the correction is unambiguous.
+///
+/// Returns `Some(corrected)` when exactly one `(position, digit)`
+/// substitution makes `input` validate, and `None` when there is no
+/// candidate or several. The result is never a guess.
+///
+/// In practice this only corrects single-digit input. For an invalid number
+/// of length `n`, each position has exactly one replacement digit that
+/// fixes the checksum (every row of the group table is a bijection), so
+/// [`find_single_error_candidates`] always returns `n` candidates and the
+/// correction is ambiguous from two digits upwards. A valid number has no
+/// candidates and also yields `None`. Use [`solve_unknown`] when the
+/// position of the error is known.
+///
+/// # Returns
+///
+/// * `Ok(Some(String))` - The unique correction
+/// * `Ok(None)` - If the input is valid or the correction is ambiguous
+/// * `Err(VerhoeffError)` - If the input is empty or contains non-digits
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::correct_single_error;
+///
+/// assert_eq!(correct_single_error("7"), Ok(Some("0".to_string())));
+/// assert_eq!(correct_single_error("123491"), Ok(None));
+/// ```
+pub fn correct_single_error(input: &str) -> Result<Option<String>> {
+    check_digits(input)?;
+
+    match find_single_error_candidates(input)[..] {
+        [(position, digit)] => Ok(Some(substitute(input, position, digit))),
+        _ => Ok(None),
+    }
+}
+
+/// Check whether changing one digit could make `input` valid.
+///
+/// Returns `true` exactly when [`find_single_error_candidates`] has at
+/// least one candidate. Because every row of the group table is a
+/// bijection, each position of an invalid number has exactly one fixing
+/// digit, so for the standard tables this is `true` for every invalid
+/// number and `false` for every valid one: a valid number is zero
+/// corrections away, and any single change breaks it. Costs O(len * 10)
+/// table lookups.
+///
+/// # Returns
+///
+/// * `Ok(bool)` - Whether a single-digit substitution makes `input` valid
+/// * `Err(VerhoeffError)` - If the input is empty or contains non-digits
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::is_one_correction_away;
+///
+/// assert_eq!(is_one_correction_away("123491"), Ok(true));
+/// assert_eq!(is_one_correction_away("123451"), Ok(false));
+/// assert!(is_one_correction_away("1234-1").is_err());
+/// ```
+pub fn is_one_correction_away(input: &str) -> Result<bool> {
+    check_digits(input)?;
+    Ok(!single_error_candidates_in(&STANDARD, input).is_empty())
+}
+
+/// List every valid number reachable from `input` by changing one digit.
+///
+/// The results are ordered by the position of the changed digit, left to
+/// right. An invalid number of length `n` yields `n` strings, one per
+/// position, and a valid number yields none. Costs O(len * 10) table
+/// lookups plus one `String` per result.
+///
+/// # Returns
+///
+/// * `Ok(Vec<String>)` - The valid numbers one substitution away
+/// * `Err(VerhoeffError)` - If the input is empty or contains non-digits
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::nearest_valid_by_single_substitution;
+///
+/// // "123451" mistyped as "123491"
+/// let nearest = nearest_valid_by_single_substitution("123491")?;
+/// assert_eq!(nearest.len(), 6);
+/// assert!(nearest.contains(&"123451".to_string()));
+/// # Ok::<(), verhoeff::VerhoeffError>(())
+/// ```
+pub fn nearest_valid_by_single_substitution(input: &str) -> Result<Vec<String>> {
+    check_digits(input)?;
+
+    Ok(single_error_candidates_in(&STANDARD, input)
+        .into_iter()
+        .map(|(position, digit)| substitute(input, position, digit))
+        .collect())
+}
+
+/// Copies checked `input` with the digit at `position` replaced by `digit`
+fn substitute(input: &str, position: usize, digit: u8) -> String {
+    let mut replaced = String::with_capacity(input.len());
+    replaced.push_str(&input[..position]);
+    replaced.push(char::from(b'0' + digit));
+    replaced.push_str(&input[position + 1..]);
+    replaced
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{append_checksum, validate};
+
+    /// Reference implementation that revalidates every substitution
+    fn brute_force(input: &str) -> Vec<(usize, u8)> {
+        let mut found = Vec::new();
+        for position in 0..input.len() {
+            for replacement in 0..10u8 {
+                let mut bytes = input.as_bytes().to_vec();
+                if bytes[position] == b'0' + replacement {
+                    continue;
+                }
+                bytes[position] = b'0' + replacement;
+                if validate(core::str::from_utf8(&bytes).unwrap()) {
+                    found.push((position, replacement));
+                }
+            }
+        }
+        found
+    }
+
+    #[test]
+    fn test_original_digit_is_a_candidate() {
+        let full = append_checksum("8473643095");
+
+        for position in 0..full.len() {
+            let original = full.as_bytes()[position] - b'0';
+            for wrong in (0..10u8).filter(|&d| d != original) {
+                let mut bytes = full.as_bytes().to_vec();
+                bytes[position] = b'0' + wrong;
+                let corrupted = core::str::from_utf8(&bytes).unwrap();
+
+                let candidates = find_single_error_candidates(corrupted);
+                assert!(
+                    candidates.contains(&(position, original)),
+                    "Missing ({position}, {original}) for '{corrupted}'"
+                );
+                assert_eq!(candidates, brute_force(corrupted));
+            }
+        }
+    }
+
+    #[test]
+    fn test_all_positions_critical_for_standard_tables() {
+        for input in ["1", "236", "12345678901", "00000000000000000"] {
+            let expected: Vec<usize> = (0..input.len()).collect();
+            assert_eq!(critical_positions(input).unwrap(), expected);
+        }
+        assert_eq!(critical_positions(""), Err(VerhoeffError::EmptyInput));
+    }
+
+    #[test]
+    fn test_degenerate_table_has_insensitive_positions() {
+        // Collapse permutation row 1 to a constant, so the rightmost base
+        // digit (which uses row 1) no longer affects the check digit
+        let mut p = P_TABLE;
+        p[1] = [0; 10];
+        let degenerate = Tables {
+            d: &D_TABLE,
+            p: &p,
+            inv: &INV_TABLE,
+        };
+
+        assert_eq!(
+            critical_positions_in(&degenerate, "12345").unwrap(),
+            vec![0, 1, 2, 3]
+        );
+        // Row 1 recurs every 8 positions
+        assert_eq!(
+            critical_positions_in(&degenerate, "1234567890").unwrap(),
+            vec![0, 2, 3, 4, 5, 6, 7, 8]
+        );
+    }
+
+    #[test]
+    fn test_no_undetectable_single_errors() {
+        for base in [
+            "0",
+            "236",
+            "12345",
+            "8473643095",
+            "99999999999",
+            "1234567890123456",
+        ] {
+            let full = append_checksum(base);
+            assert_eq!(undetectable_single_errors(&full), Ok(vec![]), "{full}");
+        }
+
+        assert_eq!(
+            undetectable_single_errors("2364"),
+            Err(VerhoeffError::ChecksumMismatch {
+                expected: 3,
+                found: 4
+            })
+        );
+        assert_eq!(
+            undetectable_single_errors(""),
+            Err(VerhoeffError::EmptyInput)
+        );
+    }
+
+    #[test]
+    fn test_degenerate_table_has_undetectable_errors() {
+        // With row 1 constant, the digit at reverse position 1 of a full
+        // number can be replaced by anything without changing the product
+        let mut p = P_TABLE;
+        p[1] = [0; 10];
+        let degenerate = Tables {
+            d: &D_TABLE,
+            p: &p,
+            inv: &INV_TABLE,
+        };
+
+        let undetected = single_error_candidates_in(&degenerate, "00");
+        let expected: Vec<(usize, u8)> = (1..10).map(|digit| (0, digit)).collect();
+        assert_eq!(undetected, expected);
+    }
+
+    #[test]
+    fn test_no_weak_transpositions() {
+        for base in ["1", "236", "12345", "8473643095", "1234567890123456", "90"] {
+            let full = append_checksum(base);
+            assert_eq!(weakest_transposition(&full), Ok(None), "{full}");
+        }
+
+        // Nothing to swap in a single digit or a run of equal digits
+        assert_eq!(weakest_transposition("0"), Ok(None));
+        assert_eq!(weakest_transposition(&append_checksum("1111")), Ok(None));
+
+        assert!(matches!(
+            weakest_transposition("2364"),
+            Err(VerhoeffError::ChecksumMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_commutative_scheme_misses_transpositions() {
+        // Addition mod 10 with no permutations is commutative, so every
+        // adjacent swap keeps the sum, like a plain digit-sum check
+        let z10: [[u8; 10]; 10] =
+            core::array::from_fn(|a| core::array::from_fn(|b| ((a + b) % 10) as u8));
+        let identity: [u8; 10] = core::array::from_fn(|x| x as u8);
+        let inv: [u8; 10] = core::array::from_fn(|x| ((10 - x) % 10) as u8);
+        let weak = Tables {
+            d: &z10,
+            p: &[identity; 8],
+            inv: &inv,
+        };
+
+        assert_eq!(weakest_transposition_in(&weak, "19"), Some((0, 1)));
+        assert_eq!(weakest_transposition_in(&weak, "5528"), Some((1, 2)));
+        assert_eq!(weakest_transposition_in(&weak, "55"), None);
+    }
+
+    #[test]
+    fn test_weakest_transposition_matches_brute_force() {
+        // Z10 with the Verhoeff permutations catches some swaps but not all
+        let z10: [[u8; 10]; 10] =
+            core::array::from_fn(|a| core::array::from_fn(|b| ((a + b) % 10) as u8));
+        let inv: [u8; 10] = core::array::from_fn(|x| ((10 - x) % 10) as u8);
+        let tables = Tables {
+            d: &z10,
+            p: &P_TABLE,
+            inv: &inv,
+        };
+        let validates = |digits: &[u8]| {
+            let c = digits.iter().rev().enumerate().fold(0, |c, (i, &b)| {
+                tables.d[c as usize][tables.p[i % 8][(b - b'0') as usize] as usize]
+            });
+            c == 0
+        };
+
+        let mut found = 0;
+        for i in 0..10_000u32 {
+            let mut digits = alloc::format!("{i:05}").into_bytes();
+            let fix = (0..10u8).find(|&d| {
+                digits.push(b'0' + d);
+                let ok = validates(&digits);
+                digits.pop();
+                ok
+            });
+            digits.push(b'0' + fix.unwrap());
+
+            let expected = (0..digits.len() - 1).find(|&j| {
+                let mut swapped = digits.clone();
+                swapped.swap(j, j + 1);
+                swapped != digits && validates(&swapped)
+            });
+            let input = core::str::from_utf8(&digits).unwrap();
+            let actual = weakest_transposition_in(&tables, input);
+            assert_eq!(actual, expected.map(|j| (j, j + 1)), "{input}");
+            found += actual.is_some() as u32;
+        }
+        assert!(found > 0);
+    }
+
+    #[test]
+    fn test_one_correction_away() {
+        let full = append_checksum("8473643095");
+        assert_eq!(is_one_correction_away(&full), Ok(false));
+        assert_eq!(nearest_valid_by_single_substitution(&full), Ok(Vec::new()));
+
+        for position in 0..full.len() {
+            let mut bytes = full.as_bytes().to_vec();
+            bytes[position] = b'0' + (bytes[position] - b'0' + 3) % 10;
+            let corrupted = core::str::from_utf8(&bytes).unwrap();
+
+            assert_eq!(is_one_correction_away(corrupted), Ok(true));
+            let nearest = nearest_valid_by_single_substitution(corrupted).unwrap();
+            assert_eq!(nearest.len(), full.len());
+            assert!(nearest.contains(&full), "{corrupted}");
+            for candidate in &nearest {
+                assert!(validate(candidate), "{candidate}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_one_correction_away_rejects_malformed_input() {
+        assert_eq!(is_one_correction_away(""), Err(VerhoeffError::EmptyInput));
+        assert_eq!(
+            is_one_correction_away("8473-643095"),
+            Err(VerhoeffError::InvalidCharacter { ch: '-', index: 4 })
+        );
+        assert_eq!(
+            nearest_valid_by_single_substitution("ABCD"),
+            Err(VerhoeffError::InvalidCharacter { ch: 'A', index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_nearest_valid_is_ordered_by_position() {
+        let nearest = nearest_valid_by_single_substitution("123491").unwrap();
+        for (position, candidate) in nearest.iter().enumerate() {
+            let differs: Vec<usize> = candidate
+                .bytes()
+                .zip("123491".bytes())
+                .enumerate()
+                .filter(|(_, (a, b))| a != b)
+                .map(|(i, _)| i)
+                .collect();
+            assert_eq!(differs, [position]);
+        }
+    }
+
+    #[test]
+    fn test_simulate_errors_guarantees() {
+        for base in ["236", "12345", "8473643095", "1234567890123"] {
+            let full = append_checksum(base);
+            let results = simulate_errors(
+                &full,
+                &[
+                    ErrorKind::SingleSubstitution,
+                    ErrorKind::AdjacentTransposition,
+                ],
+            )
+            .unwrap();
+
+            assert!(results.len() >= 9 * full.len());
+            for (corrupted, detected) in results {
+                assert!(detected, "{corrupted} from {full}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_simulate_errors_jump_transpositions_can_slip() {
+        let missed = (0..1000u32)
+            .map(|i| append_checksum(&alloc::format!("{i:03}")))
+            .flat_map(|full| simulate_errors(&full, &[ErrorKind::JumpTransposition]).unwrap())
+            .filter(|(_, detected)| !detected)
+            .count();
+        assert!(missed > 0, "every jump transposition was detected");
+    }
+
+    #[test]
+    fn test_simulate_errors_variants() {
+        let results =
+            simulate_errors("2363", &[ErrorKind::JumpTransposition, ErrorKind::Omission]).unwrap();
+        let corrupted: Vec<&str> = results.iter().map(|(s, _)| s.as_str()).collect();
+        assert_eq!(corrupted, ["6323", "363", "263", "233", "236"]);
+
+        assert!(simulate_errors("2363", &[]).unwrap().is_empty());
+        assert!(matches!(
+            simulate_errors("2364", &[ErrorKind::Omission]),
+            Err(VerhoeffError::ChecksumMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_solve_unknown_recovers_blanked_digit() {
+        let full = append_checksum("8473643095");
+
+        for (position, original) in full.char_indices() {
+            let mut blanked = alloc::string::String::from(&full[..position]);
+            blanked.push('?');
+            blanked.push_str(&full[position + 1..]);
+
+            let solutions = solve_unknown(&blanked, '?').unwrap();
+            assert_eq!(solutions, vec![original as u8 - b'0'], "'{blanked}'");
+        }
+    }
+
+    #[test]
+    fn test_solve_unknown_errors() {
+        assert_eq!(
+            solve_unknown("123451", '?'),
+            Err(VerhoeffError::InvalidPlaceholderCount(0))
+        );
+        assert_eq!(
+            solve_unknown("1?34?1", '?'),
+            Err(VerhoeffError::InvalidPlaceholderCount(2))
+        );
+        assert_eq!(
+            solve_unknown("12?4a1", '?'),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 4 })
+        );
+        assert_eq!(solve_unknown("", '?'), Err(VerhoeffError::EmptyInput));
+
+        // Any character can serve as the placeholder
+        assert_eq!(solve_unknown("12_451", '_'), Ok(vec![3]));
+        assert_eq!(solve_unknown("12□451", '□'), Ok(vec![3]));
+    }
+
+    #[test]
+    fn test_correct_single_error() {
+        // The only valid single digit is 0, so any other is fixed uniquely
+        for wrong in '1'..='9' {
+            let input = String::from(wrong);
+            assert_eq!(correct_single_error(&input), Ok(Some("0".into())));
+        }
+
+        // Longer numbers always have one candidate per position, so a
+        // one-digit corruption is never auto-corrected
+        let full = append_checksum("8473643095");
+        for position in 0..full.len() {
+            let mut bytes = full.as_bytes().to_vec();
+            bytes[position] = b'0' + (bytes[position] - b'0' + 1) % 10;
+            let corrupted = core::str::from_utf8(&bytes).unwrap();
+
+            assert_eq!(find_single_error_candidates(corrupted).len(), full.len());
+            assert_eq!(correct_single_error(corrupted), Ok(None), "'{corrupted}'");
+        }
+
+        // Valid input has nothing to correct
+        assert_eq!(correct_single_error("0"), Ok(None));
+        assert_eq!(correct_single_error(&full), Ok(None));
+
+        assert_eq!(correct_single_error(""), Err(VerhoeffError::EmptyInput));
+        assert_eq!(
+            correct_single_error("12a"),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 2 })
+        );
+    }
+
+    #[test]
+    fn test_valid_and_malformed_inputs() {
+        // A valid number has no single-substitution neighbour that validates
+        assert!(find_single_error_candidates("123451").is_empty());
+        assert!(find_single_error_candidates("").is_empty());
+        assert!(find_single_error_candidates("12a451").is_empty());
+    }
+}
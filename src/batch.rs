@@ -0,0 +1,220 @@
+// FilePath: src/batch.rs
+
+//! Helpers for validating many numbers at once
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::{calculate_checksum_result, validate, validate_result, Result};
+
+/// Validate many numbers independently, one result per item.
+///
+/// Results are returned in the same order as the input, and a malformed
+/// entry only affects its own result. The per-item validation never
+/// allocates, so the only allocation is the result vector.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{validate_batch, VerhoeffError};
+///
+/// let results = validate_batch(["2363", "2364", ""]);
+/// assert_eq!(results, vec![Ok(true), Ok(false), Err(VerhoeffError::EmptyInput)]);
+/// ```
+pub fn validate_batch<I, S>(items: I) -> Vec<Result<bool>>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    items
+        .into_iter()
+        .map(|item| validate_result(item.as_ref()))
+        .collect()
+}
+
+/// Validate many numbers in parallel, one result per item.
+///
+/// Equivalent to [`validate_batch`], but fans validation out across the
+/// rayon thread pool. The input is collected first so that results can be
+/// returned in input order. Validation is stateless and only reads `const`
+/// tables, so items are processed fully independently.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{validate_batch, validate_batch_par};
+///
+/// let items = ["2363", "2364", "12a45"];
+/// assert_eq!(validate_batch_par(items), validate_batch(items));
+/// ```
+#[cfg(feature = "rayon")]
+pub fn validate_batch_par<I, S>(items: I) -> Vec<Result<bool>>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str> + Sync,
+{
+    use rayon::prelude::*;
+
+    let items: Vec<S> = items.into_iter().collect();
+    items
+        .par_iter()
+        .map(|item| validate_result(item.as_ref()))
+        .collect()
+}
+
+/// Find the index ranges of consecutive invalid or malformed entries.
+///
+/// Each returned range covers a maximal run of entries that fail
+/// [`validate`], in input order. Clustered failures often point to systemic
+/// corruption, such as a truncated section of a file, rather than isolated
+/// typos.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::invalid_runs;
+///
+/// let inputs = ["2363", "2364", "12a", "123451", "0"];
+/// assert_eq!(invalid_runs(&inputs), vec![1..3]);
+/// ```
+pub fn invalid_runs(inputs: &[&str]) -> Vec<Range<usize>> {
+    let mut runs = Vec::new();
+    let mut start = None;
+
+    for (i, input) in inputs.iter().enumerate() {
+        match (validate(input), start) {
+            (false, None) => start = Some(i),
+            (true, Some(s)) => {
+                runs.push(s..i);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(s) = start {
+        runs.push(s..inputs.len());
+    }
+
+    runs
+}
+
+/// Count how often each check digit occurs over a set of base numbers.
+///
+/// Each item is a number without its check digit. The returned array holds,
+/// at index `d`, the number of items whose check digit is `d`. Healthy data
+/// spreads roughly evenly over the ten digits, so a skewed histogram is a
+/// sign of generated or corrupted IDs.
+///
+/// # Returns
+///
+/// * `Ok([u64; 10])` - The count for each check digit
+/// * `Err(VerhoeffError)` - For the first malformed item
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::checksum_histogram;
+///
+/// let counts = checksum_histogram(["236", "12345", "1"])?;
+/// assert_eq!(counts, [0, 1, 0, 1, 0, 1, 0, 0, 0, 0]);
+/// # Ok::<(), verhoeff::VerhoeffError>(())
+/// ```
+pub fn checksum_histogram<I, S>(items: I) -> Result<[u64; 10]>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut counts = [0u64; 10];
+    for item in items {
+        counts[calculate_checksum_result(item.as_ref())? as usize] += 1;
+    }
+    Ok(counts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VerhoeffError;
+    use alloc::string::String;
+
+    #[test]
+    fn test_validate_batch() {
+        let items = vec![
+            String::from("123456789010"),
+            String::from("123456789013"),
+            String::from("12a45"),
+            String::new(),
+            String::from("2363"),
+        ];
+
+        assert_eq!(
+            validate_batch(&items),
+            vec![
+                Ok(true),
+                Ok(false),
+                Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 2 }),
+                Err(VerhoeffError::EmptyInput),
+                Ok(true),
+            ]
+        );
+        assert!(validate_batch(Vec::<&str>::new()).is_empty());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_validate_batch_par_matches_sequential() {
+        use alloc::format;
+
+        let items: Vec<String> = (0..10_000u32)
+            .map(|i| match i % 4 {
+                0 => crate::append_checksum(&format!("{i}")),
+                1 => format!("{i}"),
+                2 => format!("{i}x"),
+                _ => String::new(),
+            })
+            .collect();
+
+        assert_eq!(validate_batch_par(&items), validate_batch(&items));
+    }
+
+    #[test]
+    fn test_checksum_histogram() {
+        use alloc::format;
+
+        let counts = checksum_histogram((0..10_000).map(|i| format!("{i:08}"))).unwrap();
+        assert_eq!(counts.iter().sum::<u64>(), 10_000);
+
+        // Every digit appears, within 50% of the expected 1000
+        for (digit, &count) in counts.iter().enumerate() {
+            assert!(count > 500 && count < 1500, "digit {digit}: {count}");
+        }
+
+        assert_eq!(checksum_histogram(Vec::<&str>::new()), Ok([0; 10]));
+        assert_eq!(
+            checksum_histogram(["123", "", "456"]),
+            Err(VerhoeffError::EmptyInput)
+        );
+    }
+
+    #[test]
+    fn test_invalid_runs() {
+        let inputs = [
+            "2363",   // valid
+            "2364",   // invalid checksum
+            "",       // malformed
+            "12a45",  // malformed
+            "123451", // valid
+            "1428570", "1428571", // invalid checksum
+            "999",     // invalid checksum
+        ];
+        assert_eq!(invalid_runs(&inputs), vec![1..4, 6..8]);
+    }
+
+    #[test]
+    fn test_invalid_runs_edges() {
+        assert!(invalid_runs(&[]).is_empty());
+        assert!(invalid_runs(&["2363", "123451"]).is_empty());
+        assert_eq!(invalid_runs(&["x", "y"]), vec![0..2]);
+    }
+}
@@ -0,0 +1,148 @@
+// FilePath: src/bin/verhoeff.rs
+
+//! Command-line front end for the Verhoeff checksum library
+//!
+//! ```text
+//! verhoeff calc <number>        print the check digit
+//! verhoeff validate <number>    exit 0 if valid, 1 if invalid
+//! verhoeff append <number>      print the number with its check digit
+//! verhoeff report <number>      print a validation report with corrections
+//! verhoeff <command> --stdin    apply the command to each line of stdin
+//! ```
+//!
+//! `report` exits like `validate`. Malformed input prints the error to stderr
+//! and exits with status 2.
+//!
+//! With `--stdin`, each line produces exactly one line of output (a block
+//! for `report`), so the results line up with the input: a malformed line
+//! prints `error`, and its message goes to stderr prefixed with the 1-based
+//! line number. Lines are taken as read, without trimming, exactly as by
+//! `validate_reader`.
+
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+
+use verhoeff::{calculate_checksum_result, validate_result, VerhoeffError};
+
+const USAGE: &str = "usage: verhoeff <calc|validate|append|report> <number|--stdin>";
+
+/// The subcommands, each a thin wrapper over one library function
+#[derive(Clone, Copy)]
+enum Command {
+    Calc,
+    Validate,
+    Append,
+    Report,
+}
+
+/// What a command produced for one number
+enum Outcome {
+    Valid(String),
+    Invalid(String),
+    Error(VerhoeffError),
+}
+
+impl Command {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "calc" => Some(Command::Calc),
+            "validate" => Some(Command::Validate),
+            "append" => Some(Command::Append),
+            "report" => Some(Command::Report),
+            _ => None,
+        }
+    }
+
+    fn run(self, input: &str) -> Outcome {
+        let result = match self {
+            Command::Calc => {
+                calculate_checksum_result(input).map(|d| Outcome::Valid(d.to_string()))
+            }
+            Command::Append => {
+                calculate_checksum_result(input).map(|d| Outcome::Valid(format!("{input}{d}")))
+            }
+            Command::Validate => validate_result(input).map(|valid| {
+                if valid {
+                    Outcome::Valid("valid".into())
+                } else {
+                    Outcome::Invalid("invalid".into())
+                }
+            }),
+            Command::Report => {
+                let report = verhoeff::report(input);
+                match report.error() {
+                    Some(e) => Err(e.clone()),
+                    None if report.is_valid() => Ok(Outcome::Valid(report.to_string())),
+                    None => Ok(Outcome::Invalid(report.to_string())),
+                }
+            }
+        };
+        result.unwrap_or_else(Outcome::Error)
+    }
+}
+
+/// Exit status for the worst outcome seen: 0 valid, 1 invalid, 2 error
+fn status(outcome: &Outcome) -> u8 {
+    match outcome {
+        Outcome::Valid(_) => 0,
+        Outcome::Invalid(_) => 1,
+        Outcome::Error(_) => 2,
+    }
+}
+
+/// Prints an outcome, returning its exit status.
+///
+/// An error for stdin line `line` also prints `error` to stdout, keeping
+/// the output aligned with the input.
+fn report(outcome: Outcome, line: Option<usize>, out: &mut impl Write) -> io::Result<u8> {
+    let code = status(&outcome);
+    match (outcome, line) {
+        (Outcome::Valid(text) | Outcome::Invalid(text), _) => writeln!(out, "{text}")?,
+        (Outcome::Error(e), None) => eprintln!("error: {e}"),
+        (Outcome::Error(e), Some(line)) => {
+            writeln!(out, "error")?;
+            eprintln!("error: line {line}: {e}");
+        }
+    }
+    Ok(code)
+}
+
+fn run() -> io::Result<u8> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let [name, input] = args.as_slice() else {
+        eprintln!("{USAGE}");
+        return Ok(2);
+    };
+    let Some(command) = Command::parse(name) else {
+        eprintln!("{USAGE}");
+        return Ok(2);
+    };
+
+    let mut out = io::stdout().lock();
+    if input != "--stdin" {
+        return report(command.run(input), None, &mut out);
+    }
+
+    let mut worst = 0;
+    for (i, line) in io::stdin().lock().lines().enumerate() {
+        let outcome = match line {
+            Ok(line) => command.run(&line),
+            // A line that is not UTF-8 is reported like any malformed line
+            Err(e) if e.kind() == io::ErrorKind::InvalidData => Outcome::Error(VerhoeffError::Io),
+            Err(e) => return Err(e),
+        };
+        let code = report(outcome, Some(i + 1), &mut out)?;
+        worst = worst.max(code);
+    }
+    Ok(worst)
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(code) => ExitCode::from(code),
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::from(2)
+        }
+    }
+}
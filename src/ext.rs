@@ -0,0 +1,116 @@
+// FilePath: src/ext.rs
+
+//! Method-call syntax for the core functions
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+#[cfg(feature = "alloc")]
+use crate::append_checksum_result;
+use crate::{calculate_checksum_result, validate_result, Result};
+
+/// Verhoeff operations as methods on strings.
+///
+/// Each method is sugar for the free function it names and behaves
+/// identically, so malformed input is reported rather than hidden.
+///
+/// The trait is sealed: it is implemented for `str` and `String` only, so
+/// that `verhoeff_append`, which exists only with the `alloc` feature, can
+/// never break an implementation outside this crate.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::Checksummable;
+///
+/// assert_eq!("12345".verhoeff_checksum(), Ok(1));
+/// assert_eq!("123451".verhoeff_validate(), Ok(true));
+/// assert!("12a45".verhoeff_checksum().is_err());
+///
+/// # #[cfg(feature = "alloc")]
+/// # {
+/// let id = String::from("12345");
+/// assert_eq!(id.verhoeff_append(), Ok("123451".to_string()));
+/// # }
+/// ```
+pub trait Checksummable: sealed::Sealed {
+    /// The check digit of this number, as [`calculate_checksum_result`].
+    fn verhoeff_checksum(&self) -> Result<u8>;
+
+    /// Whether this number ends in a valid check digit, as
+    /// [`validate_result`].
+    fn verhoeff_validate(&self) -> Result<bool>;
+
+    /// This number with its check digit appended, as
+    /// `append_checksum_result`.
+    #[cfg(feature = "alloc")]
+    fn verhoeff_append(&self) -> Result<String>;
+}
+
+mod sealed {
+    /// Keeps [`Checksummable`](super::Checksummable) from being implemented
+    /// outside this crate
+    pub trait Sealed {}
+
+    impl Sealed for str {}
+
+    #[cfg(feature = "alloc")]
+    impl Sealed for alloc::string::String {}
+}
+
+impl Checksummable for str {
+    fn verhoeff_checksum(&self) -> Result<u8> {
+        calculate_checksum_result(self)
+    }
+
+    fn verhoeff_validate(&self) -> Result<bool> {
+        validate_result(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn verhoeff_append(&self) -> Result<String> {
+        append_checksum_result(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Checksummable for String {
+    fn verhoeff_checksum(&self) -> Result<u8> {
+        self.as_str().verhoeff_checksum()
+    }
+
+    fn verhoeff_validate(&self) -> Result<bool> {
+        self.as_str().verhoeff_validate()
+    }
+
+    fn verhoeff_append(&self) -> Result<String> {
+        self.as_str().verhoeff_append()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUTS: [&str; 7] = ["", "0", "236", "2363", "12345", "123452", "12a45"];
+
+    #[test]
+    fn test_methods_agree_with_free_functions() {
+        for input in INPUTS {
+            assert_eq!(input.verhoeff_checksum(), calculate_checksum_result(input));
+            assert_eq!(input.verhoeff_validate(), validate_result(input));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_string_methods_agree_with_free_functions() {
+        for input in INPUTS {
+            let owned = String::from(input);
+            assert_eq!(owned.verhoeff_checksum(), calculate_checksum_result(input));
+            assert_eq!(owned.verhoeff_validate(), validate_result(input));
+            assert_eq!(owned.verhoeff_append(), append_checksum_result(input));
+            assert_eq!(input.verhoeff_append(), append_checksum_result(input));
+        }
+    }
+}
@@ -0,0 +1,178 @@
+// FilePath: src/ffi.rs
+
+//! C-compatible bindings, enabled by the `ffi` feature
+//!
+//! Every function takes a pointer to a NUL-terminated string that must be
+//! valid UTF-8; in practice only the ASCII digits `0`-`9` are accepted, so any
+//! other content is reported as an error. A null pointer is also an error.
+//! Nothing is allocated and no function panics across the FFI boundary.
+//!
+//! To link from C, build the library with an extra crate type, for example
+//! `cargo rustc --release --features ffi --crate-type cdylib`, and declare
+//! the functions as in `include/verhoeff.h`. A standalone C library needs a
+//! panic handler, so keep the default `std` feature for such builds.
+
+use core::ffi::{c_char, c_int, CStr};
+
+use crate::{calculate_checksum_result, validate_result};
+
+/// Returned for null, non-UTF-8, empty or non-digit input
+const ERR_INPUT: c_int = -1;
+
+/// Returned by [`verhoeff_append`] when the output buffer is too small
+const ERR_BUFFER: c_int = -2;
+
+/// Borrows a NUL-terminated C string as `&str`, or `None` if it is null or
+/// not valid UTF-8.
+///
+/// # Safety
+///
+/// `ptr` must be null or point to a NUL-terminated string that outlives the
+/// returned reference.
+unsafe fn input_str<'a>(ptr: *const c_char) -> Option<&'a str> {
+    if ptr.is_null() {
+        return None;
+    }
+    unsafe { CStr::from_ptr(ptr) }.to_str().ok()
+}
+
+/// Calculate the Verhoeff check digit of a NUL-terminated digit string.
+///
+/// # Returns
+///
+/// The check digit `0`-`9`, or `-1` on error.
+///
+/// # Safety
+///
+/// `ptr` must be null or point to a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn verhoeff_calculate(ptr: *const c_char) -> c_int {
+    match unsafe { input_str(ptr) }.map(calculate_checksum_result) {
+        Some(Ok(digit)) => c_int::from(digit),
+        _ => ERR_INPUT,
+    }
+}
+
+/// Validate a NUL-terminated digit string ending in its check digit.
+///
+/// # Returns
+///
+/// `1` if valid, `0` if the checksum is wrong, or `-1` on error.
+///
+/// # Safety
+///
+/// `ptr` must be null or point to a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn verhoeff_validate(ptr: *const c_char) -> c_int {
+    match unsafe { input_str(ptr) }.map(validate_result) {
+        Some(Ok(valid)) => c_int::from(valid),
+        _ => ERR_INPUT,
+    }
+}
+
+/// Write a digit string followed by its check digit into `out_buf`.
+///
+/// The output is NUL-terminated, so `out_len` must be at least
+/// `strlen(input) + 2` bytes. On error nothing is written.
+///
+/// # Returns
+///
+/// The length of the written string excluding the NUL terminator, `-1` on
+/// invalid input, or `-2` if `out_buf` is null or too small.
+///
+/// # Safety
+///
+/// `ptr` must be null or point to a valid NUL-terminated string, and
+/// `out_buf` must be null or valid for writes of `out_len` bytes. The two
+/// must not overlap.
+#[no_mangle]
+pub unsafe extern "C" fn verhoeff_append(
+    ptr: *const c_char,
+    out_buf: *mut c_char,
+    out_len: usize,
+) -> c_int {
+    let Some(input) = (unsafe { input_str(ptr) }) else {
+        return ERR_INPUT;
+    };
+    let Ok(digit) = calculate_checksum_result(input) else {
+        return ERR_INPUT;
+    };
+
+    let len = input.len() + 1;
+    let Ok(ret) = c_int::try_from(len) else {
+        return ERR_INPUT;
+    };
+    if out_buf.is_null() || out_len < len + 1 {
+        return ERR_BUFFER;
+    }
+
+    let out = unsafe { core::slice::from_raw_parts_mut(out_buf.cast::<u8>(), len + 1) };
+    out[..input.len()].copy_from_slice(input.as_bytes());
+    out[input.len()] = b'0' + digit;
+    out[len] = 0;
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+    use std::ptr;
+
+    fn c(s: &str) -> CString {
+        CString::new(s).unwrap()
+    }
+
+    #[test]
+    fn test_calculate() {
+        unsafe {
+            assert_eq!(verhoeff_calculate(c("12345").as_ptr()), 1);
+            assert_eq!(verhoeff_calculate(c("236").as_ptr()), 3);
+            assert_eq!(verhoeff_calculate(c("").as_ptr()), -1);
+            assert_eq!(verhoeff_calculate(c("12a").as_ptr()), -1);
+            assert_eq!(verhoeff_calculate(ptr::null()), -1);
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        unsafe {
+            assert_eq!(verhoeff_validate(c("123451").as_ptr()), 1);
+            assert_eq!(verhoeff_validate(c("123452").as_ptr()), 0);
+            assert_eq!(verhoeff_validate(c("").as_ptr()), -1);
+            assert_eq!(verhoeff_validate(ptr::null()), -1);
+
+            // Invalid UTF-8
+            let bytes = [0xFFu8, 0];
+            assert_eq!(verhoeff_validate(bytes.as_ptr().cast()), -1);
+        }
+    }
+
+    #[test]
+    fn test_append() {
+        let input = c("12345");
+        let mut buf = [0x7F as c_char; 8];
+
+        unsafe {
+            assert_eq!(
+                verhoeff_append(input.as_ptr(), buf.as_mut_ptr(), buf.len()),
+                6
+            );
+            assert_eq!(CStr::from_ptr(buf.as_ptr()).to_str(), Ok("123451"));
+
+            // Exactly strlen + 2 bytes is enough; one fewer is not
+            assert_eq!(verhoeff_append(input.as_ptr(), buf.as_mut_ptr(), 7), 6);
+            assert_eq!(verhoeff_append(input.as_ptr(), buf.as_mut_ptr(), 6), -2);
+            assert_eq!(verhoeff_append(input.as_ptr(), ptr::null_mut(), 8), -2);
+
+            assert_eq!(
+                verhoeff_append(c("12x").as_ptr(), buf.as_mut_ptr(), buf.len()),
+                -1
+            );
+            assert_eq!(
+                verhoeff_append(ptr::null(), buf.as_mut_ptr(), buf.len()),
+                -1
+            );
+        }
+    }
+}
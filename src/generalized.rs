@@ -0,0 +1,361 @@
+// FilePath: src/generalized.rs
+
+//! Verhoeff-style check digits over an arbitrary finite group
+//!
+//! The free functions of this crate are the canonical instantiation over the
+//! dihedral group D₅ with the tables in [`tables`](crate::tables).
+//! [`GeneralizedVerhoeff`] runs the same algorithm over user-supplied tables
+//! so other groups and permutation schedules can be explored.
+
+use core::fmt;
+
+use crate::{Result, VerhoeffError, D_TABLE, INV_TABLE, P_TABLE};
+
+/// Error types for rejected [`GeneralizedVerhoeff`] tables
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableError {
+    /// A row or column of the multiplication table is not a permutation of
+    /// `0..N`, so the table is not a quasigroup
+    NotQuasigroup,
+    /// 0 is not a two-sided identity of the multiplication table
+    NoIdentity,
+    /// The multiplication table is not associative, so it is not a group
+    NotAssociative,
+    /// `inv_table[element]` is not the inverse of `element`
+    InvalidInverse {
+        /// The element whose inverse is wrong
+        element: u8,
+    },
+    /// The permutation table has no rows
+    EmptyPermutationTable,
+    /// A row of the permutation table is not a permutation of `0..N`
+    NotPermutation {
+        /// The offending row
+        row: usize,
+    },
+}
+
+impl fmt::Display for TableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TableError::NotQuasigroup => {
+                write!(f, "Multiplication table is not a quasigroup")
+            }
+            TableError::NoIdentity => {
+                write!(f, "0 is not the identity of the multiplication table")
+            }
+            TableError::NotAssociative => {
+                write!(f, "Multiplication table is not associative")
+            }
+            TableError::InvalidInverse { element } => {
+                write!(f, "Inverse table is wrong for element {element}")
+            }
+            TableError::EmptyPermutationTable => {
+                write!(f, "Permutation table must have at least one row")
+            }
+            TableError::NotPermutation { row } => {
+                write!(f, "Permutation table row {row} is not a permutation")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TableError {}
+
+/// A Verhoeff-style scheme over an `N`-element group, applying the `P` rows
+/// of its permutation table periodically.
+///
+/// Digits are values `0..N`. As in the standard algorithm, the digit at
+/// reverse position `i` (rightmost first) is permuted by row `i % P` and
+/// folded into the running product, and a number is valid when the product
+/// is the identity 0.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{tables, GeneralizedVerhoeff};
+///
+/// let scheme = GeneralizedVerhoeff::new(tables::D_TABLE, tables::P_TABLE, tables::INV_TABLE)?;
+/// assert_eq!(scheme.calculate_checksum(&[1, 2, 3, 4, 5]), Ok(1));
+/// assert_eq!(scheme.validate(&[1, 2, 3, 4, 5, 1]), Ok(true));
+/// # Ok::<(), verhoeff::TableError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneralizedVerhoeff<const N: usize, const P: usize> {
+    d_table: [[u8; N]; N],
+    p_table: [[u8; N]; P],
+    inv_table: [u8; N],
+    /// Inverse of permutation row 0, which is applied to the check digit
+    p0_inv: [u8; N],
+}
+
+impl GeneralizedVerhoeff<10, 8> {
+    /// The canonical D₅ scheme used by the free functions of this crate.
+    pub const fn standard() -> Self {
+        GeneralizedVerhoeff {
+            d_table: D_TABLE,
+            p_table: P_TABLE,
+            inv_table: INV_TABLE,
+            p0_inv: [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+        }
+    }
+}
+
+impl<const N: usize, const P: usize> GeneralizedVerhoeff<N, P> {
+    /// Create a scheme from its tables, checking at runtime that they
+    /// define a group with inverses and a valid permutation schedule.
+    ///
+    /// # Arguments
+    ///
+    /// * `d_table` - The group multiplication table, with identity 0
+    /// * `p_table` - `P` permutations of `0..N`, applied periodically
+    /// * `inv_table` - The inverse of each element under `d_table`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(GeneralizedVerhoeff)` - If the tables are consistent
+    /// * `Err(TableError)` - Describing the first problem found
+    pub fn new(
+        d_table: [[u8; N]; N],
+        p_table: [[u8; N]; P],
+        inv_table: [u8; N],
+    ) -> Result<Self, TableError> {
+        check_tables(&d_table, &p_table, &inv_table)?;
+
+        let mut p0_inv = [0u8; N];
+        for (x, &y) in p_table[0].iter().enumerate() {
+            p0_inv[y as usize] = x as u8;
+        }
+
+        Ok(GeneralizedVerhoeff {
+            d_table,
+            p_table,
+            inv_table,
+            p0_inv,
+        })
+    }
+
+    /// Calculate the check digit to append to `digits`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u8)` - The check digit, in `0..N`
+    /// * `Err(VerhoeffError::InvalidDigitValue)` - If a value is not below `N`
+    /// * `Err(VerhoeffError::EmptyInput)` - If `digits` is empty
+    pub fn calculate_checksum(&self, digits: &[u8]) -> Result<u8> {
+        let c = self.accumulate(digits, 1)?;
+        Ok(self.p0_inv[self.inv_table[c as usize] as usize])
+    }
+
+    /// Validate `digits` ending in their check digit.
+    ///
+    /// Follows the same rules as [`calculate_checksum`](Self::calculate_checksum).
+    pub fn validate(&self, digits: &[u8]) -> Result<bool> {
+        Ok(self.accumulate(digits, 0)? == 0)
+    }
+
+    /// Folds `digits` (rightmost first) into the running product, applying
+    /// permutation row `(i + offset) % P` to the digit at reverse position `i`
+    fn accumulate(&self, digits: &[u8], offset: usize) -> Result<u8> {
+        if digits.is_empty() {
+            return Err(VerhoeffError::EmptyInput);
+        }
+
+        let mut c = 0u8;
+        for (i, &digit) in digits.iter().rev().enumerate() {
+            if digit as usize >= N {
+                return Err(VerhoeffError::InvalidDigitValue(digit));
+            }
+            let permuted = self.p_table[(i + offset) % P][digit as usize];
+            c = self.d_table[c as usize][permuted as usize];
+        }
+        Ok(c)
+    }
+}
+
+/// Checks that the three tables define a group with inverses and a valid
+/// permutation schedule, reporting the first problem found
+pub(crate) fn check_tables<const N: usize, const P: usize>(
+    d_table: &[[u8; N]; N],
+    p_table: &[[u8; N]; P],
+    inv_table: &[u8; N],
+) -> Result<(), TableError> {
+    check_group(d_table)?;
+
+    for (element, &inverse) in inv_table.iter().enumerate() {
+        if inverse as usize >= N || d_table[inverse as usize][element] != 0 {
+            return Err(TableError::InvalidInverse {
+                element: element as u8,
+            });
+        }
+    }
+
+    if P == 0 {
+        return Err(TableError::EmptyPermutationTable);
+    }
+    for (row, perm) in p_table.iter().enumerate() {
+        if !is_permutation(perm) {
+            return Err(TableError::NotPermutation { row });
+        }
+    }
+    Ok(())
+}
+
+/// Whether `row` holds every value in `0..N` exactly once
+fn is_permutation<const N: usize>(row: &[u8; N]) -> bool {
+    let mut seen = [false; N];
+    for &value in row {
+        match seen.get_mut(value as usize) {
+            Some(slot) if !*slot => *slot = true,
+            _ => return false,
+        }
+    }
+    true
+}
+
+/// Checks that `d` is a group with identity 0: a quasigroup (Latin square)
+/// with a two-sided identity that is also associative
+fn check_group<const N: usize>(d: &[[u8; N]; N]) -> Result<(), TableError> {
+    for (i, row) in d.iter().enumerate() {
+        let column: [u8; N] = core::array::from_fn(|j| d[j][i]);
+        if !is_permutation(row) || !is_permutation(&column) {
+            return Err(TableError::NotQuasigroup);
+        }
+    }
+
+    if N == 0 || (0..N).any(|x| d[0][x] as usize != x || d[x][0] as usize != x) {
+        return Err(TableError::NoIdentity);
+    }
+
+    for a in 0..N {
+        for b in 0..N {
+            for c in 0..N {
+                let ab = d[a][b] as usize;
+                let bc = d[b][c] as usize;
+                if d[ab][c] != d[a][bc] {
+                    return Err(TableError::NotAssociative);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{calculate_checksum, validate};
+
+    fn to_digits(s: &str) -> Vec<u8> {
+        s.bytes().map(|b| b - b'0').collect()
+    }
+
+    /// Addition modulo 5, a cyclic group
+    fn z5() -> [[u8; 5]; 5] {
+        core::array::from_fn(|a| core::array::from_fn(|b| ((a + b) % 5) as u8))
+    }
+
+    #[test]
+    fn test_standard_tables_match_free_functions() {
+        let scheme = GeneralizedVerhoeff::new(D_TABLE, P_TABLE, INV_TABLE).unwrap();
+        assert_eq!(scheme, GeneralizedVerhoeff::standard());
+
+        let inputs = ["0", "236", "12345", "142857", "12345678901", "8473643095"];
+        for input in inputs {
+            let digits = to_digits(input);
+            assert_eq!(
+                scheme.calculate_checksum(&digits),
+                Ok(calculate_checksum(input)),
+                "{input}"
+            );
+            assert_eq!(scheme.validate(&digits), Ok(validate(input)), "{input}");
+        }
+
+        let long = "9876543210".repeat(17);
+        assert_eq!(
+            scheme.calculate_checksum(&to_digits(&long)),
+            Ok(calculate_checksum(&long))
+        );
+    }
+
+    #[test]
+    fn test_other_group_round_trips() {
+        // Z5 with a schedule whose check row is not the identity
+        let p = [[1, 2, 3, 4, 0], [0, 2, 4, 1, 3], [0, 3, 1, 4, 2]];
+        let inv = [0, 4, 3, 2, 1];
+        let scheme = GeneralizedVerhoeff::new(z5(), p, inv).unwrap();
+
+        for base in [&[1u8][..], &[4, 4, 4], &[0, 1, 2, 3, 4, 0, 1]] {
+            let check = scheme.calculate_checksum(base).unwrap();
+            let mut full = base.to_vec();
+            full.push(check);
+            assert_eq!(scheme.validate(&full), Ok(true), "{full:?}");
+
+            // Z5 is abelian, but single-digit errors are still caught
+            full[0] = (full[0] + 1) % 5;
+            assert_eq!(scheme.validate(&full), Ok(false), "{full:?}");
+        }
+    }
+
+    #[test]
+    fn test_digit_errors() {
+        let scheme = GeneralizedVerhoeff::new(z5(), [[0, 1, 2, 3, 4]], [0, 4, 3, 2, 1]).unwrap();
+        assert_eq!(
+            scheme.calculate_checksum(&[1, 5]),
+            Err(VerhoeffError::InvalidDigitValue(5))
+        );
+        assert_eq!(scheme.validate(&[]), Err(VerhoeffError::EmptyInput));
+    }
+
+    #[test]
+    fn test_rejects_bad_tables() {
+        let id = [[0, 1, 2, 3, 4]];
+        let inv = [0, 4, 3, 2, 1];
+
+        let mut not_latin = z5();
+        not_latin[1][1] = 1;
+        assert_eq!(
+            GeneralizedVerhoeff::new(not_latin, id, inv),
+            Err(TableError::NotQuasigroup)
+        );
+
+        // Z5 relabelled so that 1 is the identity
+        let shifted: [[u8; 5]; 5] =
+            core::array::from_fn(|a| core::array::from_fn(|b| ((a + b + 4) % 5) as u8));
+        assert_eq!(
+            GeneralizedVerhoeff::new(shifted, id, inv),
+            Err(TableError::NoIdentity)
+        );
+
+        // The smallest loop that is not a group
+        let table = [
+            [0, 1, 2, 3, 4],
+            [1, 0, 3, 4, 2],
+            [2, 4, 0, 1, 3],
+            [3, 2, 4, 0, 1],
+            [4, 3, 1, 2, 0],
+        ];
+        assert_eq!(
+            GeneralizedVerhoeff::new(table, id, [0, 1, 2, 3, 4]),
+            Err(TableError::NotAssociative)
+        );
+
+        assert_eq!(
+            GeneralizedVerhoeff::new(z5(), id, [0, 4, 3, 1, 2]),
+            Err(TableError::InvalidInverse { element: 3 })
+        );
+        assert_eq!(
+            GeneralizedVerhoeff::new(z5(), id, [0, 4, 3, 2, 9]),
+            Err(TableError::InvalidInverse { element: 4 })
+        );
+        assert_eq!(
+            GeneralizedVerhoeff::new(z5(), [[0u8; 5]; 0], inv),
+            Err(TableError::EmptyPermutationTable)
+        );
+        assert_eq!(
+            GeneralizedVerhoeff::new(z5(), [[0, 1, 2, 3, 4], [0, 1, 1, 3, 4]], inv),
+            Err(TableError::NotPermutation { row: 1 })
+        );
+    }
+}
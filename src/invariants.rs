@@ -0,0 +1,154 @@
+// FilePath: src/invariants.rs
+
+//! Reusable assertions of the Verhoeff guarantees, enabled by the `testing`
+//! feature
+//!
+//! Each function checks one property the crate's own tests rely on and
+//! panics with a descriptive message if it does not hold, so they can be
+//! called directly from downstream fuzz targets, property tests and
+//! integration tests.
+//!
+//! ```
+//! use verhoeff::invariants;
+//!
+//! invariants::assert_roundtrip("8473643095");
+//! invariants::assert_single_digit_detected("123456789010");
+//! invariants::assert_adjacent_transposition_detected("123456789010");
+//! ```
+
+use alloc::vec::Vec;
+use core::str;
+
+use crate::{append_checksum_result, calculate_checksum_result, split_checksum, validate_result};
+
+/// Assert that appending the check digit to `base` gives a number that
+/// validates and splits back into `base` and that digit.
+///
+/// # Panics
+///
+/// Panics if `base` is malformed or any step of the round trip disagrees.
+pub fn assert_roundtrip(base: &str) {
+    let checksum = calculate_checksum_result(base)
+        .unwrap_or_else(|e| panic!("cannot compute the check digit of {base:?}: {e}"));
+    let full = append_checksum_result(base)
+        .unwrap_or_else(|e| panic!("cannot append a check digit to {base:?}: {e}"));
+
+    assert_eq!(
+        validate_result(&full),
+        Ok(true),
+        "{full:?} does not validate after appending {checksum} to {base:?}"
+    );
+    assert_eq!(
+        split_checksum(&full),
+        Ok((base, checksum)),
+        "{full:?} does not split back into {base:?} and {checksum}"
+    );
+}
+
+/// Assert that every single-digit substitution in the valid number `full`
+/// is detected.
+///
+/// # Panics
+///
+/// Panics if `full` does not validate, or if replacing any one digit with a
+/// different digit still validates.
+pub fn assert_single_digit_detected(full: &str) {
+    assert_valid(full);
+
+    let mut digits: Vec<u8> = full.bytes().collect();
+    for position in 0..digits.len() {
+        let original = digits[position];
+        for replacement in (b'0'..=b'9').filter(|&d| d != original) {
+            digits[position] = replacement;
+            assert_eq!(
+                validate_result(as_str(&digits)),
+                Ok(false),
+                "replacing {} with {} at position {position} of {full:?} went undetected",
+                char::from(original),
+                char::from(replacement)
+            );
+        }
+        digits[position] = original;
+    }
+}
+
+/// Assert that swapping any two adjacent, differing digits of the valid
+/// number `full` is detected.
+///
+/// # Panics
+///
+/// Panics if `full` does not validate, or if any such transposition still
+/// validates.
+pub fn assert_adjacent_transposition_detected(full: &str) {
+    assert_valid(full);
+
+    let mut digits: Vec<u8> = full.bytes().collect();
+    for position in 0..digits.len().saturating_sub(1) {
+        if digits[position] == digits[position + 1] {
+            continue;
+        }
+        digits.swap(position, position + 1);
+        assert_eq!(
+            validate_result(as_str(&digits)),
+            Ok(false),
+            "swapping positions {position} and {} of {full:?} went undetected",
+            position + 1
+        );
+        digits.swap(position, position + 1);
+    }
+}
+
+/// Panics unless `full` is a valid number
+fn assert_valid(full: &str) {
+    assert_eq!(
+        validate_result(full),
+        Ok(true),
+        "{full:?} is not a valid Verhoeff number"
+    );
+}
+
+/// Views a buffer of ASCII digits as a string
+fn as_str(digits: &[u8]) -> &str {
+    str::from_utf8(digits).expect("digits are ASCII")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use alloc::string::String;
+
+    #[test]
+    fn test_invariants_hold() {
+        let fixed = [
+            "0",
+            "1",
+            "236",
+            "12345",
+            "142857",
+            "8473643095",
+            "99999999999",
+        ];
+        let generated = (0..10_000u32).step_by(97).map(|i| format!("{i:08}"));
+
+        for base in fixed.into_iter().map(String::from).chain(generated) {
+            assert_roundtrip(&base);
+
+            let full = append_checksum_result(&base).unwrap();
+            assert_single_digit_detected(&full);
+            assert_adjacent_transposition_detected(&full);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a valid Verhoeff number")]
+    fn test_invalid_number_rejected() {
+        assert_single_digit_detected("123452");
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot compute the check digit")]
+    fn test_malformed_base_rejected() {
+        assert_roundtrip("12a");
+    }
+}
@@ -11,14 +11,49 @@
 //!
 //! - Calculate Verhoeff checksum digit
 //! - Validate numbers with Verhoeff checksum
-//! - Specialized Aadhaar validation
+//! - Specialized Aadhaar and Virtual ID validation, formatting and masking
+//! - Verhoeff-style schemes over other groups via [`GeneralizedVerhoeff`]
+//! - Luhn check digits in the [`luhn`] module, for interop and comparison
+//! - Opt-in acceptance of Unicode decimal digits, such as Devanagari, via
+//!   [`validate_unicode`]
+//! - Incremental validation of streamed digits via [`VerhoeffState`]
+//! - A `VerhoeffNumber` type that can only hold checksum-valid numbers
 //! - No external dependencies
+//! - `no_std` support, with the core API working without an allocator
 //! - Zero-cost abstractions with const lookup tables
 //!
+//! ## Feature flags
+//!
+//! - `std` (default) - implements `std::error::Error` for [`VerhoeffError`]
+//!   and enables the `HashSet`-based helpers; implies `alloc`
+//! - `aadhaar` (default) - Aadhaar and Virtual ID validation, plus the
+//!   `format_aadhaar`, `mask_aadhaar` and `to_display_string` helpers when
+//!   `alloc` is also enabled
+//! - `alloc` - enables the helpers that return `String`, such as
+//!   `append_checksum`, and the `VerhoeffNumber` type
+//! - `ffi` - `extern "C"` functions for calling the crate from C, in the
+//!   `ffi` module
+//! - `futures` - `validate_stream`, the async counterpart of
+//!   `validate_reader`, over any `Stream` of lines
+//! - `macros` - the `verhoeff_checked!` macro, which validates a number
+//!   literal at compile time
+//! - `rand` - generation of synthetic, checksum-valid numbers
+//! - `rayon` - parallel batch validation with `validate_batch_par`
+//! - `serde` - `Serialize`/`Deserialize` for `VerhoeffNumber`; deserializing
+//!   validates the checksum. Also `Serialize` for [`VerhoeffError`]
+//! - `tokio` - `validate_async`, which validates the lines of a tokio
+//!   `AsyncBufRead`; implies `futures`
+//! - `testing` - the `invariants` module of reusable assertions for
+//!   downstream fuzz and property tests
+//! - `wasm` - `wasm-bindgen` wrappers for JavaScript, in the `wasm` module
+//!
+//! With `default-features = false` the crate is `#![no_std]` and the
+//! checksum and validation functions never allocate.
+//!
 //! ## Example
 //!
 //! ```
-//! use verhoeff::{calculate_checksum, validate, validate_aadhaar};
+//! use verhoeff::{calculate_checksum, validate};
 //!
 //! // Calculate checksum digit
 //! let checksum = calculate_checksum("12345678901");
@@ -27,85 +62,451 @@
 //! // Validate a number with checksum
 //! let is_valid = validate("123456789012");
 //! println!("Is valid: {}", is_valid);
+//! ```
+//!
+//! With the `aadhaar` feature:
+//!
+//! ```
+//! # #[cfg(feature = "aadhaar")]
+//! # {
+//! use verhoeff::validate_aadhaar;
 //!
 //! // Validate Aadhaar number
 //! match validate_aadhaar("123456789012") {
 //!     Ok(valid) => println!("Aadhaar valid: {}", valid),
 //!     Err(e) => println!("Validation error: {}", e),
 //! }
+//! # }
 //! ```
 
-use std::fmt;
-
-/// Multiplication table (d) based on the dihedral group D₅
-const D_TABLE: [[u8; 10]; 10] = [
-    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
-    [1, 2, 3, 4, 0, 6, 7, 8, 9, 5],
-    [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
-    [3, 4, 0, 1, 2, 8, 9, 5, 6, 7],
-    [4, 0, 1, 2, 3, 9, 5, 6, 7, 8],
-    [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
-    [6, 5, 9, 8, 7, 1, 0, 4, 3, 2],
-    [7, 6, 5, 9, 8, 2, 1, 0, 4, 3],
-    [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
-    [9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
-];
-
-/// Permutation table (p) - position-dependent permutations
-const P_TABLE: [[u8; 10]; 8] = [
-    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
-    [1, 5, 7, 6, 2, 8, 3, 0, 9, 4],
-    [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
-    [8, 9, 1, 6, 0, 4, 3, 5, 2, 7],
-    [9, 4, 5, 3, 1, 2, 6, 8, 7, 0],
-    [4, 2, 8, 6, 5, 7, 3, 9, 0, 1],
-    [2, 7, 9, 3, 8, 0, 6, 4, 1, 5],
-    [7, 0, 4, 6, 9, 1, 3, 2, 5, 8],
-];
-
-/// Inverse table (inv) for finding the inverse of a digit
-const INV_TABLE: [u8; 10] = [0, 4, 3, 2, 1, 5, 6, 7, 8, 9];
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+#[cfg(feature = "aadhaar")]
+mod aadhaar;
+#[cfg(feature = "alloc")]
+mod analysis;
+#[cfg(feature = "alloc")]
+mod audit;
+#[cfg(feature = "alloc")]
+mod batch;
+mod ext;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod generalized;
+#[cfg(feature = "testing")]
+pub mod invariants;
+pub mod luhn;
+mod masked;
+#[cfg(feature = "std")]
+mod membership;
+mod normalize;
+#[cfg(feature = "alloc")]
+mod number;
+mod options;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "std")]
+mod reader;
+#[cfg(feature = "alloc")]
+mod report;
+mod spec;
+mod state;
+pub mod tables;
+mod unicode;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(all(feature = "aadhaar", feature = "alloc"))]
+pub use aadhaar::{format_aadhaar, mask_aadhaar, mask_aadhaar_with, to_display_string};
+#[cfg(feature = "aadhaar")]
+pub use aadhaar::{validate_aadhaar, validate_aadhaar_strict, validate_vid, Scheme, AADHAAR, VID};
+#[cfg(feature = "alloc")]
+pub use analysis::{
+    correct_single_error, critical_positions, find_single_error_candidates, is_one_correction_away,
+    nearest_valid_by_single_substitution, simulate_errors, solve_unknown,
+    undetectable_single_errors, weakest_transposition, ErrorKind,
+};
+#[cfg(feature = "alloc")]
+pub use audit::{validate_audited, AuditEvent, AuditSink};
+#[cfg(feature = "rayon")]
+pub use batch::validate_batch_par;
+#[cfg(feature = "alloc")]
+pub use batch::{checksum_histogram, invalid_runs, validate_batch};
+pub use ext::Checksummable;
+pub use generalized::{GeneralizedVerhoeff, TableError};
+pub use masked::Masked;
+#[cfg(feature = "std")]
+pub use membership::{validate_not_blocklisted, BlocklistResult, VerhoeffSet};
+#[cfg(feature = "alloc")]
+pub use normalize::normalize_digits;
+pub use normalize::{
+    calculate_checksum_with_separators, digits_equal, validate_normalized, validate_with_separators,
+};
+#[cfg(feature = "alloc")]
+pub use number::VerhoeffNumber;
+pub use options::ValidateOptions;
+#[cfg(all(feature = "rand", feature = "aadhaar"))]
+pub use random::random_aadhaar;
+#[cfg(feature = "rand")]
+pub use random::random_number;
+#[cfg(feature = "tokio")]
+pub use reader::validate_async;
+#[cfg(feature = "std")]
+pub use reader::validate_reader;
+#[cfg(feature = "futures")]
+pub use reader::validate_stream;
+#[cfg(feature = "alloc")]
+pub use report::{report, Report};
+pub use spec::IdSpec;
+pub use state::{calculate_checksum_iter, validate_iter, VerhoeffState};
+pub use unicode::{
+    calculate_checksum_mapped, calculate_checksum_unicode, validate_mapped, validate_unicode,
+};
+#[cfg(feature = "macros")]
+pub use verhoeff_macros::verhoeff_checked;
+
+use tables::{D_TABLE, INV_TABLE, PD_TABLE, P_TABLE};
+
+/// The result type returned by the fallible functions of this crate.
+///
+/// The error type defaults to [`VerhoeffError`], so downstream code can write
+/// `verhoeff::Result<u8>`; a second parameter still names any other error.
+///
+/// # Example
+///
+/// ```
+/// fn check_digit_of(input: &str) -> verhoeff::Result<u8> {
+///     let base = verhoeff::validate_and_strip(input)?;
+///     verhoeff::calculate_checksum_result(base)
+/// }
+///
+/// assert_eq!(check_digit_of("123451"), Ok(1));
+/// ```
+pub type Result<T, E = VerhoeffError> = core::result::Result<T, E>;
 
 /// Error types for Verhoeff validation
 #[derive(Debug, Clone, PartialEq)]
 pub enum VerhoeffError {
-    /// Input contains non-digit characters
-    InvalidCharacter(char),
+    /// Input contains a non-digit character at the given zero-based position
+    ///
+    /// Only ASCII `0`-`9` are digits for the strict functions, so
+    /// look-alikes such as fullwidth `１`, superscript `¹` or a combining mark
+    /// after a digit are reported here unchanged. The message adds the code
+    /// point of any character that would not print legibly on its own, e.g.
+    /// `Invalid character '１' (U+FF11) at position 0`.
+    InvalidCharacter {
+        /// The offending character, exactly as it appeared in the input
+        ch: char,
+        /// Zero-based character position within the input
+        index: usize,
+    },
+    /// Like `InvalidCharacter`, but the input as a whole looks like a
+    /// hexadecimal or base-36 code rather than a mistyped decimal number
+    LikelyNonDecimal {
+        /// The first non-digit character
+        ch: char,
+        /// Zero-based character position within the input
+        index: usize,
+    },
     /// Input is empty
     EmptyInput,
+    /// Input was not empty, but no digits remained after stripping
+    /// separators or other formatting
+    EmptyAfterNormalization,
     /// Invalid length for Aadhaar (must be 12 digits)
     InvalidAadhaarLength(usize),
+    /// Invalid length for a Virtual ID (must be 16 digits)
+    InvalidVidLength(usize),
+    /// An Aadhaar number starts with this digit, but UIDAI only issues
+    /// numbers starting with 2-9
+    InvalidAadhaarLeadingDigit(u8),
+    /// Input is not the required number of characters
+    InvalidLength {
+        /// The required length, including the check digit
+        expected: usize,
+        /// The length of the input, in characters
+        got: usize,
+    },
+    /// The length prefix of a number does not match the digits that follow
+    LengthPrefixMismatch {
+        /// The payload length declared by the prefix
+        declared: usize,
+        /// The number of digits between the prefix and the check digit
+        got: usize,
+    },
+    /// Input does not have the length required by a named [`IdSpec`]
+    InvalidIdLength {
+        /// The name of the spec
+        name: &'static str,
+        /// The required length, including the check digit
+        expected: usize,
+        /// The length of the input, in characters
+        got: usize,
+    },
+    /// A numeric digit value outside the range 0-9
+    InvalidDigitValue(u8),
+    /// Input must contain exactly one placeholder character, but had this many
+    InvalidPlaceholderCount(usize),
+    /// A position lies outside the input
+    IndexOutOfBounds {
+        /// The requested position
+        index: usize,
+        /// The length of the input
+        len: usize,
+    },
+    /// A digit was given as a separator, which would make the output ambiguous
+    InvalidSeparator(char),
+    /// The base of a valid number does not fit in the requested integer type
+    IntegerOverflow {
+        /// The width of the integer type, in bits
+        bits: u32,
+    },
+    /// Reading the input failed, for example because a line of a stream was
+    /// not valid UTF-8
+    Io,
+    /// The trailing check digit does not match the one computed over the base
+    ChecksumMismatch {
+        /// The check digit computed over the base
+        expected: u8,
+        /// The check digit present in the input
+        found: u8,
+    },
 }
 
 impl fmt::Display for VerhoeffError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            VerhoeffError::InvalidCharacter(c) => {
-                write!(f, "Invalid character '{c}' - only digits allowed")
+            VerhoeffError::InvalidCharacter { ch, index } => {
+                write!(f, "Invalid character '{ch}'")?;
+                if !(ch.is_ascii_graphic() || *ch == ' ') {
+                    write!(f, " (U+{:04X})", u32::from(*ch))?;
+                }
+                write!(f, " at position {index} - only digits allowed")
+            }
+            VerhoeffError::LikelyNonDecimal { ch, index } => {
+                write!(
+                    f,
+                    "Invalid character '{ch}' at position {index} - input appears to be non-decimal (base-N?), only digits allowed"
+                )
             }
             VerhoeffError::EmptyInput => write!(f, "Input cannot be empty"),
+            VerhoeffError::EmptyAfterNormalization => {
+                write!(f, "Input contains no digits after removing separators")
+            }
             VerhoeffError::InvalidAadhaarLength(len) => {
                 write!(f, "Aadhaar numbers must be 12 digits, got {len} digits")
             }
+            VerhoeffError::InvalidVidLength(len) => {
+                write!(f, "Virtual IDs must be 16 digits, got {len} digits")
+            }
+            VerhoeffError::InvalidAadhaarLeadingDigit(digit) => {
+                write!(
+                    f,
+                    "Aadhaar numbers cannot start with {digit} - the first digit must be 2-9"
+                )
+            }
+            VerhoeffError::InvalidLength { expected, got } => {
+                write!(f, "Expected {expected} digits, got {got}")
+            }
+            VerhoeffError::LengthPrefixMismatch { declared, got } => {
+                write!(f, "Length prefix declares {declared} digits, got {got}")
+            }
+            VerhoeffError::InvalidIdLength {
+                name,
+                expected,
+                got,
+            } => {
+                write!(f, "{name} must be {expected} digits, got {got} digits")
+            }
+            VerhoeffError::InvalidDigitValue(value) => {
+                write!(f, "Invalid digit value {value} - must be between 0 and 9")
+            }
+            VerhoeffError::InvalidPlaceholderCount(count) => {
+                write!(f, "Expected exactly one placeholder, found {count}")
+            }
+            VerhoeffError::IndexOutOfBounds { index, len } => {
+                write!(f, "Index {index} is out of bounds for length {len}")
+            }
+            VerhoeffError::InvalidSeparator(sep) => {
+                write!(f, "Invalid separator '{sep}' - separators cannot be digits")
+            }
+            VerhoeffError::IntegerOverflow { bits } => {
+                write!(f, "Number does not fit in a {bits}-bit integer")
+            }
+            VerhoeffError::Io => write!(f, "Failed to read input"),
+            VerhoeffError::ChecksumMismatch { expected, found } => {
+                write!(
+                    f,
+                    "Checksum mismatch - expected check digit {expected}, found {found}"
+                )
+            }
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for VerhoeffError {}
 
-/// Converts a string of digits into a vector of u8 values
-fn string_to_digits(s: &str) -> Result<Vec<u8>, VerhoeffError> {
+/// Converts to an [`ErrorKind::InvalidData`](std::io::ErrorKind::InvalidData)
+/// error with the same message, keeping the original as its inner error, so
+/// `?` works in functions returning `io::Result`.
+#[cfg(feature = "std")]
+impl From<VerhoeffError> for std::io::Error {
+    fn from(err: VerhoeffError) -> Self {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, err)
+    }
+}
+
+/// Serializes as an internally tagged map: a `"type"` key naming the variant
+/// plus one key per field, e.g.
+/// `{"type":"InvalidCharacter","char":"O","index":11}`. The character field
+/// of `InvalidCharacter`, `LikelyNonDecimal` and `InvalidSeparator` is
+/// `"char"`, and the payload of the single-value variants is named: `"got"`
+/// for the length errors, `"digit"`, `"value"` and `"count"`. New fields
+/// may be added, so consumers should ignore keys they do not know.
+#[cfg(feature = "serde")]
+impl serde::Serialize for VerhoeffError {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        macro_rules! tagged {
+            ($tag:literal, $len:literal $(, $key:literal => $value:expr)*) => {{
+                let mut state = serializer.serialize_struct("VerhoeffError", $len)?;
+                state.serialize_field("type", $tag)?;
+                $(state.serialize_field($key, $value)?;)*
+                state.end()
+            }};
+        }
+
+        match self {
+            VerhoeffError::InvalidCharacter { ch, index } => {
+                tagged!("InvalidCharacter", 3, "char" => ch, "index" => index)
+            }
+            VerhoeffError::LikelyNonDecimal { ch, index } => {
+                tagged!("LikelyNonDecimal", 3, "char" => ch, "index" => index)
+            }
+            VerhoeffError::EmptyInput => tagged!("EmptyInput", 1),
+            VerhoeffError::EmptyAfterNormalization => tagged!("EmptyAfterNormalization", 1),
+            VerhoeffError::InvalidAadhaarLength(got) => {
+                tagged!("InvalidAadhaarLength", 2, "got" => got)
+            }
+            VerhoeffError::InvalidVidLength(got) => tagged!("InvalidVidLength", 2, "got" => got),
+            VerhoeffError::InvalidAadhaarLeadingDigit(digit) => {
+                tagged!("InvalidAadhaarLeadingDigit", 2, "digit" => digit)
+            }
+            VerhoeffError::InvalidLength { expected, got } => {
+                tagged!("InvalidLength", 3, "expected" => expected, "got" => got)
+            }
+            VerhoeffError::LengthPrefixMismatch { declared, got } => {
+                tagged!("LengthPrefixMismatch", 3, "declared" => declared, "got" => got)
+            }
+            VerhoeffError::InvalidIdLength {
+                name,
+                expected,
+                got,
+            } => {
+                tagged!("InvalidIdLength", 4, "name" => name, "expected" => expected, "got" => got)
+            }
+            VerhoeffError::InvalidDigitValue(value) => {
+                tagged!("InvalidDigitValue", 2, "value" => value)
+            }
+            VerhoeffError::InvalidPlaceholderCount(count) => {
+                tagged!("InvalidPlaceholderCount", 2, "count" => count)
+            }
+            VerhoeffError::IndexOutOfBounds { index, len } => {
+                tagged!("IndexOutOfBounds", 3, "index" => index, "len" => len)
+            }
+            VerhoeffError::InvalidSeparator(ch) => tagged!("InvalidSeparator", 2, "char" => ch),
+            VerhoeffError::IntegerOverflow { bits } => {
+                tagged!("IntegerOverflow", 2, "bits" => bits)
+            }
+            VerhoeffError::Io => tagged!("Io", 1),
+            VerhoeffError::ChecksumMismatch { expected, found } => {
+                tagged!("ChecksumMismatch", 3, "expected" => expected, "found" => found)
+            }
+        }
+    }
+}
+
+/// Checks that a string is non-empty and contains only digits, reporting the
+/// first offending character
+fn check_digits(s: &str) -> Result<()> {
     if s.is_empty() {
         return Err(VerhoeffError::EmptyInput);
     }
 
-    s.chars()
-        .map(|c| {
-            c.to_digit(10)
-                .map(|d| d as u8)
-                .ok_or(VerhoeffError::InvalidCharacter(c))
-        })
-        .collect()
+    // Scan bytes rather than chars: valid input is pure ASCII, so no char
+    // decoding is needed
+    match s.bytes().position(|b| !b.is_ascii_digit()) {
+        None => Ok(()),
+        Some(index) => {
+            // Everything before the offender is a one-byte digit, so its byte
+            // offset is also its char position; decode just that char
+            let ch = s[index..].chars().next().unwrap_or_default();
+            Err(VerhoeffError::InvalidCharacter { ch, index })
+        }
+    }
+}
+
+/// Insert `sep` between every `size` characters of an ASCII digit string
+#[cfg(feature = "alloc")]
+fn group_digits(digits: &str, size: usize, sep: char) -> String {
+    let seps = digits.len().saturating_sub(1) / size;
+    let mut grouped = String::with_capacity(digits.len() + seps * sep.len_utf8());
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && i % size == 0 {
+            grouped.push(sep);
+        }
+        grouped.push(c);
+    }
+    grouped
+}
+
+/// Iterates the digit values of an already-checked string in reverse order
+fn digits_rev(s: &str) -> impl Iterator<Item = u8> + '_ {
+    s.bytes().rev().map(|b| b - b'0')
+}
+
+/// Folds digits (rightmost first) into the accumulator `c`, applying
+/// permutation row `(i + offset) % 8` to the digit at reverse position `i`
+fn accumulate(digits: impl Iterator<Item = u8>, offset: usize, mut c: u8) -> u8 {
+    for (i, digit) in digits.enumerate() {
+        c = PD_TABLE[(i + offset) % 8][digit as usize][c as usize];
+    }
+    c
+}
+
+/// Checks and folds an ASCII digit string in a single pass over its bytes,
+/// like [`accumulate`] over [`digits_rev`] after [`check_digits`]
+fn accumulate_ascii(s: &str, offset: usize, mut c: u8) -> Result<u8> {
+    for (i, &b) in s.as_bytes().iter().rev().enumerate() {
+        let digit = b.wrapping_sub(b'0');
+        if digit > 9 {
+            // Rescan from the left so the first offender is reported
+            return check_digits(s).map(|()| c);
+        }
+        c = PD_TABLE[(i + offset) % 8][digit as usize][c as usize];
+    }
+
+    if s.is_empty() {
+        Err(VerhoeffError::EmptyInput)
+    } else {
+        Ok(c)
+    }
 }
 
 /// Calculate the Verhoeff checksum digit for a given string of digits.
@@ -140,16 +541,45 @@ pub fn calculate_checksum(input: &str) -> u8 {
 ///
 /// * `Ok(u8)` - The checksum digit (0-9)
 /// * `Err(VerhoeffError)` - If the input is invalid
-pub fn calculate_checksum_result(input: &str) -> Result<u8, VerhoeffError> {
-    let digits = string_to_digits(input)?;
-    let mut c = 0u8;
+pub fn calculate_checksum_result(input: &str) -> Result<u8> {
+    calculate_checksum_seeded(input, 0)
+}
 
-    // Process digits in reverse order
-    for (i, &digit) in digits.iter().rev().enumerate() {
-        let permuted = P_TABLE[(i + 1) % 8][digit as usize];
-        c = D_TABLE[c as usize][permuted as usize];
+/// Calculate the Verhoeff checksum digit, starting the accumulator at `seed`.
+///
+/// The standard algorithm starts the dihedral accumulator at 0, so
+/// `seed = 0` reproduces [`calculate_checksum_result`]. Seeding with the
+/// check digit of a previous record chains the two, which is a building
+/// block for hierarchical, Merkle-like ID schemes.
+///
+/// # Arguments
+///
+/// * `input` - A string containing only digits
+/// * `seed` - The initial accumulator value (0-9)
+///
+/// # Returns
+///
+/// * `Ok(u8)` - The checksum digit (0-9)
+/// * `Err(VerhoeffError)` - If the input is invalid or `seed` is above 9
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{calculate_checksum, calculate_checksum_seeded};
+///
+/// let parent = calculate_checksum("12345");
+/// let child = calculate_checksum_seeded("67890", parent).unwrap();
+/// assert_eq!(calculate_checksum_seeded("12345", 0).unwrap(), parent);
+/// # let _ = child;
+/// ```
+pub fn calculate_checksum_seeded(input: &str, seed: u8) -> Result<u8> {
+    if seed > 9 {
+        return Err(VerhoeffError::InvalidDigitValue(seed));
     }
 
+    // Process digits in reverse order
+    let c = accumulate_ascii(input, 1, seed)?;
+
     Ok(INV_TABLE[c as usize])
 }
 
@@ -187,170 +617,2665 @@ pub fn validate(input: &str) -> bool {
 /// * `Ok(true)` - If the checksum is valid
 /// * `Ok(false)` - If the checksum is invalid
 /// * `Err(VerhoeffError)` - If the input is malformed
-pub fn validate_result(input: &str) -> Result<bool, VerhoeffError> {
-    let digits = string_to_digits(input)?;
-
-    if digits.is_empty() {
-        return Ok(false);
-    }
-
-    let mut c = 0u8;
-
+pub fn validate_result(input: &str) -> Result<bool> {
     // Process all digits in reverse order
-    for (i, &digit) in digits.iter().rev().enumerate() {
-        let permuted = P_TABLE[i % 8][digit as usize];
-        c = D_TABLE[c as usize][permuted as usize];
-    }
+    let c = accumulate_ascii(input, 0, 0)?;
 
     Ok(c == 0)
 }
 
-/// Append a Verhoeff checksum digit to a number.
+/// Validate an optional number, treating blank input as absent.
 ///
-/// # Arguments
-///
-/// * `input` - A string containing only digits
+/// For optional form fields: empty or whitespace-only input yields
+/// `Ok(None)` instead of an error. Otherwise surrounding whitespace is
+/// ignored and the rest is validated strictly, as by [`validate_result`];
+/// error indices refer to the original input.
 ///
 /// # Returns
 ///
-/// The input string with the checksum digit appended
+/// * `Ok(None)` - If the input is empty or only whitespace
+/// * `Ok(Some(true))` - If the checksum is valid
+/// * `Ok(Some(false))` - If the checksum is invalid
+/// * `Err(VerhoeffError)` - If the input contains non-digits after trimming
 ///
 /// # Example
 ///
 /// ```
-/// use verhoeff::append_checksum;
+/// use verhoeff::validate_optional;
 ///
-/// let with_checksum = append_checksum("12345678901");
-/// assert_eq!(with_checksum, "123456789010");
+/// assert_eq!(validate_optional(""), Ok(None));
+/// assert_eq!(validate_optional("  "), Ok(None));
+/// assert_eq!(validate_optional(" 123451 "), Ok(Some(true)));
+/// assert!(validate_optional("12a451").is_err());
 /// ```
-pub fn append_checksum(input: &str) -> String {
-    match calculate_checksum_result(input) {
-        Ok(checksum) => format!("{input}{checksum}"),
-        Err(_) => input.to_string(),
+pub fn validate_optional(input: &str) -> Result<Option<bool>> {
+    if input.trim().is_empty() {
+        return Ok(None);
     }
+    ValidateOptions::new()
+        .trim_whitespace(true)
+        .validate(input)
+        .map(Some)
 }
 
-/// Validate an Aadhaar number (12-digit Indian government ID).
+/// Compute the running check value of a number without the final inverse.
 ///
-/// # Arguments
+/// This is the accumulator of the validation loop: it is 0 exactly when
+/// `input` is a valid number ending in its check digit, so
+/// `running_check(input)? == 0` is equivalent to `validate_result(input)?`.
+/// A nonzero value only says the input is not (yet) valid; its magnitude
+/// carries no meaning.
 ///
-/// * `aadhaar` - A string containing 12 digits
+/// For live feedback while a number is typed, keep a [`VerhoeffState`] and
+/// feed it one keystroke at a time instead of calling this on every prefix;
+/// [`VerhoeffState::check_value`] returns the same value in O(1).
 ///
-/// # Returns
+/// # Example
 ///
-/// * `Ok(true)` - If the Aadhaar number is valid
-/// * `Ok(false)` - If the checksum is invalid
-/// * `Err(VerhoeffError)` - If the format is incorrect
+/// ```
+/// use verhoeff::{running_check, VerhoeffState};
+///
+/// assert_eq!(running_check("123451"), Ok(0));
+/// assert_ne!(running_check("12345"), Ok(0));
+///
+/// let mut state = VerhoeffState::new();
+/// for key in "123451".bytes() {
+///     state.write_ascii(&[key]).unwrap();
+/// }
+/// assert_eq!(state.check_value(), 0);
+/// ```
+pub fn running_check(input: &str) -> Result<u8> {
+    accumulate_ascii(input, 0, 0)
+}
+
+/// Calculate the Verhoeff checksum digit of a slice of digit values at
+/// compile time.
+///
+/// `digits` holds digit *values* 0-9, not ASCII characters. Being a
+/// `const fn`, this can check baked-in identifiers during compilation, so a
+/// typo in a constant fails the build. An empty slice yields 0, like
+/// [`calculate_checksum`].
+///
+/// # Panics
+///
+/// Panics if any value is greater than 9. In a const context this is a
+/// compile-time error reported by the const evaluator.
 ///
 /// # Example
 ///
 /// ```
-/// use verhoeff::validate_aadhaar;
+/// use verhoeff::{calculate_checksum_const, validate_const};
 ///
-/// match validate_aadhaar("123456789012") {
-///     Ok(valid) => println!("Valid: {}", valid),
-///     Err(e) => println!("Error: {}", e),
-/// }
+/// const CHECK: u8 = calculate_checksum_const(&[1, 2, 3, 4, 5]);
+/// assert_eq!(CHECK, 1);
+///
+/// const DEVICE_ID: [u8; 6] = [1, 2, 3, 4, 5, 1];
+/// const _: () = assert!(validate_const(&DEVICE_ID));
 /// ```
-pub fn validate_aadhaar(aadhaar: &str) -> Result<bool, VerhoeffError> {
-    // Check length
-    if aadhaar.len() != 12 {
-        return Err(VerhoeffError::InvalidAadhaarLength(aadhaar.len()));
-    }
+pub const fn calculate_checksum_const(digits: &[u8]) -> u8 {
+    INV_TABLE[accumulate_const(digits, 1) as usize]
+}
 
-    // Parse all digits
-    let digits = string_to_digits(aadhaar)?;
+/// Validate a slice of digit values, including the trailing check digit, at
+/// compile time.
+///
+/// The const counterpart of [`validate`]; an empty slice is not valid.
+///
+/// # Panics
+///
+/// Panics if any value is greater than 9, which is a compile-time error in
+/// a const context.
+pub const fn validate_const(digits: &[u8]) -> bool {
+    !digits.is_empty() && accumulate_const(digits, 0) == 0
+}
 
-    // Split into number and checksum
-    let number_part = &aadhaar[..11];
-    let checksum_digit = digits[11];
+/// One step of the Verhoeff loop: fold `digit` at `position` into the
+/// accumulator `c`.
+///
+/// Returns `D_TABLE[c][P_TABLE[position % 8][digit]]`. `position` counts
+/// from the right end of the *full* number, starting at 0 for the check
+/// digit, and `c` starts at 0. Digits may be folded in any order that
+/// visits them right to left; the order matters because D₅ is not
+/// commutative.
+///
+/// * To validate a full number, fold its digits right to left with
+///   positions `0, 1, 2, ..`; it is valid if the result is 0.
+/// * To compute a check digit, fold the base's digits right to left with
+///   positions `1, 2, 3, ..`, leaving 0 for the missing check digit, then
+///   apply [`verhoeff_inverse`].
+///
+/// These are exactly the loops behind [`validate`] and
+/// [`calculate_checksum`].
+///
+/// # Panics
+///
+/// Panics if `c` or `digit` is greater than 9.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{calculate_checksum, verhoeff_inverse, verhoeff_step};
+///
+/// let mut c = 0;
+/// for (i, digit) in [1, 2, 3, 4, 5].into_iter().rev().enumerate() {
+///     c = verhoeff_step(c, digit, i + 1);
+/// }
+/// assert_eq!(verhoeff_inverse(c), calculate_checksum("12345"));
+/// ```
+pub const fn verhoeff_step(c: u8, digit: u8, position: usize) -> u8 {
+    PD_TABLE[position % 8][digit as usize][c as usize]
+}
 
-    // Calculate expected checksum
-    let expected_checksum = calculate_checksum_result(number_part)?;
+/// The check digit that completes the accumulator `c`: `INV_TABLE[c]`.
+///
+/// See [`verhoeff_step`] for how `c` is built up.
+///
+/// # Panics
+///
+/// Panics if `c` is greater than 9.
+pub const fn verhoeff_inverse(c: u8) -> u8 {
+    INV_TABLE[c as usize]
+}
 
-    Ok(expected_checksum == checksum_digit)
+/// Calculate the check digit of a fixed-size buffer of digit values.
+///
+/// For embedded code that keeps numbers in arrays such as `[u8; 11]`: each
+/// element is a digit value 0-9, not an ASCII character. Nothing is
+/// allocated and there is no `Result`; an empty array yields 0, like
+/// [`calculate_checksum_const`].
+///
+/// # Panics
+///
+/// Debug builds assert that every value is at most 9. In release builds an
+/// out-of-range value gives a meaningless result or panics.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::calculate_checksum_array;
+///
+/// assert_eq!(calculate_checksum_array(&[1, 2, 3, 4, 5]), 1);
+/// ```
+pub fn calculate_checksum_array<const N: usize>(digits: &[u8; N]) -> u8 {
+    debug_assert_digit_values(digits);
+    INV_TABLE[accumulate(digits.iter().rev().copied(), 1, 0) as usize]
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Validate a fixed-size buffer of digit values ending in its check digit.
+///
+/// The array counterpart of [`validate`], with the same rules as
+/// [`calculate_checksum_array`]. An empty array is not valid.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::validate_array;
+///
+/// let aadhaar: [u8; 12] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 0];
+/// assert!(validate_array(&aadhaar));
+/// ```
+pub fn validate_array<const N: usize>(digits: &[u8; N]) -> bool {
+    debug_assert_digit_values(digits);
+    N > 0 && accumulate(digits.iter().rev().copied(), 0, 0) == 0
+}
 
-    #[test]
-    fn test_calculate_checksum() {
-        // Test cases from known Verhoeff implementations
-        assert_eq!(calculate_checksum("236"), 3);
-        assert_eq!(calculate_checksum("12345"), 1);
-        assert_eq!(calculate_checksum("142857"), 0);
-    }
+/// Debug-only check that every value is a digit 0-9
+fn debug_assert_digit_values(digits: &[u8]) {
+    debug_assert!(
+        digits.iter().all(|&d| d <= 9),
+        "digit value out of range 0-9"
+    );
+}
 
-    #[test]
-    fn test_validate() {
-        // Valid checksums
-        assert!(validate("2363"));
-        assert!(validate("123451"));
-        assert!(validate("1428570"));
+/// Validate ASCII digits, including the trailing check digit, without
+/// checking that they are digits.
+///
+/// For throughput-critical loops over input that has already been
+/// validated elsewhere, such as fixed-width fields parsed by a caller that
+/// checked every byte. This runs only the combined-table loop and skips the
+/// per-byte digit test that [`validate`] performs.
+///
+/// # Contract
+///
+/// Every byte must be in `b'0'..=b'9'`. This is not a safety requirement:
+/// violating it never causes undefined behavior, but the result is then
+/// meaningless and the function may panic. Debug builds check the contract
+/// with a `debug_assert!`. An empty slice returns `false`.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::validate_ascii_unchecked;
+///
+/// assert!(validate_ascii_unchecked(b"123456789010"));
+/// assert!(!validate_ascii_unchecked(b"123456789013"));
+/// ```
+#[inline]
+pub fn validate_ascii_unchecked(digits: &[u8]) -> bool {
+    debug_assert!(
+        digits.iter().all(u8::is_ascii_digit),
+        "validate_ascii_unchecked called with non-digit input"
+    );
 
-        // Invalid checksums
-        assert!(!validate("2364"));
-        assert!(!validate("123450"));
-        assert!(!validate("1428571"));
+    let mut c = 0u8;
+    for (i, &b) in digits.iter().rev().enumerate() {
+        c = PD_TABLE[i % 8][b.wrapping_sub(b'0') as usize][c as usize];
     }
+    !digits.is_empty() && c == 0
+}
 
-    #[test]
-    fn test_append_checksum() {
-        assert_eq!(append_checksum("236"), "2363");
-        assert_eq!(append_checksum("12345"), "123451");
-        assert_eq!(append_checksum("142857"), "1428570");
+/// `const` form of [`accumulate`] over a slice of digit values
+const fn accumulate_const(digits: &[u8], offset: usize) -> u8 {
+    let mut c = 0u8;
+    let mut i = 0;
+    while i < digits.len() {
+        let digit = digits[digits.len() - 1 - i];
+        assert!(digit <= 9, "digit value out of range 0-9");
+        c = PD_TABLE[(i + offset) % 8][digit as usize][c as usize];
+        i += 1;
     }
+    c
+}
 
-    #[test]
-    fn test_invalid_input() {
-        // Non-digit characters
-        assert!(calculate_checksum_result("12a45").is_err());
-        assert!(validate_result("12345a").is_err());
+/// Outcome of [`validate_detailed`], separating fixable formatting problems
+/// from genuine checksum or structural failures.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum DetailedResult {
+    /// The input is valid exactly as given
+    Valid,
+    /// The input is invalid as given, but becomes valid once separators and
+    /// whitespace are stripped, so offering an auto-fix is sensible
+    RecoverableInvalid {
+        /// Why the input failed strict validation
+        reason: VerhoeffError,
+    },
+    /// The input is invalid even after canonicalization
+    FatalInvalid {
+        /// Why the (canonicalized) input failed validation
+        reason: VerhoeffError,
+    },
+}
 
-        // Empty input
-        assert!(calculate_checksum_result("").is_err());
-        assert!(validate_result("").is_err());
-    }
+/// Characters removed when canonicalizing input in [`validate_detailed`]
+#[cfg(feature = "alloc")]
+fn is_canonical_separator(c: char) -> bool {
+    c == '-' || c.is_whitespace()
+}
 
-    #[test]
-    fn test_validate_aadhaar() {
-        // Valid format but we'll test with a made-up number
-        // In real usage, you'd test with actual valid Aadhaar numbers
-        let test_number = "12345678901";
-        let checksum = calculate_checksum(test_number);
-        let full_number = format!("{test_number}{checksum}");
+/// Guess whether `input` is a code in another base, such as hexadecimal or
+/// base-36, rather than a decimal number with a typo.
+///
+/// This is a lightweight heuristic: ignoring whitespace and hyphens, the
+/// input must consist only of ASCII letters and digits, and either contain
+/// at least two letters or start with `0x` followed by more characters. A
+/// single stray letter among digits is more likely a typo and is not
+/// flagged.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::looks_like_non_decimal;
+///
+/// assert!(looks_like_non_decimal("1a2b3c"));
+/// assert!(looks_like_non_decimal("0x1F"));
+/// assert!(!looks_like_non_decimal("23a63"));
+/// assert!(!looks_like_non_decimal("123451"));
+/// ```
+pub fn looks_like_non_decimal(input: &str) -> bool {
+    let mut letters = 0;
+    for c in input.chars().filter(|&c| c != '-' && !c.is_whitespace()) {
+        if c.is_ascii_alphabetic() {
+            letters += 1;
+        } else if !c.is_ascii_digit() {
+            return false;
+        }
+    }
 
-        assert!(validate_aadhaar(&full_number).unwrap());
+    let trimmed = input.trim_start();
+    let hex_prefix = trimmed.len() > 2 && (trimmed.starts_with("0x") || trimmed.starts_with("0X"));
+    letters >= 2 || hex_prefix
+}
 
-        // Test invalid checksum
-        let invalid = format!("{test_number}9");
-        if checksum != 9 {
-            assert!(!validate_aadhaar(&invalid).unwrap());
+/// Validate a number and classify any failure as recoverable or fatal.
+///
+/// A failure is *recoverable* when the input only fails because of
+/// formatting: stripping whitespace and hyphens yields a valid number. Any
+/// other failure, including a wrong check digit, is *fatal*. This lets a UI
+/// offer an auto-fix only when it would actually help. When the input
+/// [`looks_like_non_decimal`], the reason is
+/// `VerhoeffError::LikelyNonDecimal` instead of `InvalidCharacter`, so its
+/// message hints at the wrong base.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{validate_detailed, DetailedResult};
+///
+/// assert_eq!(validate_detailed("123451"), DetailedResult::Valid);
+/// assert!(matches!(
+///     validate_detailed("123-451"),
+///     DetailedResult::RecoverableInvalid { .. }
+/// ));
+/// assert!(matches!(
+///     validate_detailed("123452"),
+///     DetailedResult::FatalInvalid { .. }
+/// ));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn validate_detailed(input: &str) -> DetailedResult {
+    let reason = match validate_checked(input) {
+        Ok(()) => return DetailedResult::Valid,
+        Err(reason @ VerhoeffError::ChecksumMismatch { .. }) => {
+            return DetailedResult::FatalInvalid { reason }
         }
+        Err(VerhoeffError::InvalidCharacter { ch, index }) if looks_like_non_decimal(input) => {
+            let reason = VerhoeffError::LikelyNonDecimal { ch, index };
+            return DetailedResult::FatalInvalid { reason };
+        }
+        Err(reason) => reason,
+    };
 
-        // Test invalid length
-        assert!(validate_aadhaar("12345").is_err());
-        assert!(validate_aadhaar("1234567890123").is_err());
-
-        // Test non-digit characters
-        assert!(validate_aadhaar("12345678901a").is_err());
+    if !input.contains(is_canonical_separator) {
+        return DetailedResult::FatalInvalid { reason };
     }
 
-    #[test]
-    fn test_single_digit_error_detection() {
-        let base = "123456789";
-        let checksum = calculate_checksum(base);
-        let full = format!("{base}{checksum}");
-
-        // Change each digit and verify it's detected
-        for i in 0..full.len() {
-            let mut chars: Vec<char> = full.chars().collect();
-            let original = chars[i].to_digit(10).unwrap();
+    let canonical: String = input
+        .chars()
+        .filter(|&c| !is_canonical_separator(c))
+        .collect();
+    if canonical.is_empty() {
+        // Only separators: say so rather than reporting empty input
+        let reason = normalize::no_digits(input);
+        return DetailedResult::FatalInvalid { reason };
+    }
+    match validate_checked(&canonical) {
+        Ok(()) => DetailedResult::RecoverableInvalid { reason },
+        Err(reason) => DetailedResult::FatalInvalid { reason },
+    }
+}
 
-            // Try changing to different digit
-            for new_digit in 0..10 {
+/// Append a Verhoeff checksum digit to a number.
+///
+/// This is the infallible convenience wrapper around
+/// [`append_checksum_result`]: if the input is empty or contains non-digits
+/// it is returned unchanged, which is indistinguishable from a successful
+/// result by length alone. Prefer [`append_checksum_result`] for untrusted
+/// input.
+///
+/// # Arguments
+///
+/// * `input` - A string containing only digits
+///
+/// # Returns
+///
+/// The input string with the checksum digit appended
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::append_checksum;
+///
+/// let with_checksum = append_checksum("12345678901");
+/// assert_eq!(with_checksum, "123456789010");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn append_checksum(input: &str) -> String {
+    append_checksum_result(input).unwrap_or_else(|_| input.to_string())
+}
+
+/// Append a Verhoeff checksum digit to a number, reporting malformed input.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The input with its check digit appended
+/// * `Err(VerhoeffError)` - If the input is empty or contains non-digits
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{append_checksum_result, VerhoeffError};
+///
+/// assert_eq!(append_checksum_result("12345"), Ok("123451".to_string()));
+/// assert_eq!(
+///     append_checksum_result("12a45"),
+///     Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 2 })
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn append_checksum_result(input: &str) -> Result<String> {
+    let checksum = calculate_checksum_result(input)?;
+    Ok(format!("{input}{checksum}"))
+}
+
+/// Calculate the check digit and return it alongside an owned copy of the
+/// base.
+///
+/// For storage layouts that keep the check digit in its own column: the
+/// base is returned unchanged and the digit as a number, so nothing has to
+/// be split back apart. Use [`calculate_checksum_result`] instead when the
+/// base is already owned.
+///
+/// # Returns
+///
+/// * `Ok((String, u8))` - The base and its check digit
+/// * `Err(VerhoeffError)` - If the input is empty or contains non-digits
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::checksum_and_base;
+///
+/// assert_eq!(checksum_and_base("12345"), Ok(("12345".to_string(), 1)));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn checksum_and_base(input: &str) -> Result<(String, u8)> {
+    let checksum = calculate_checksum_result(input)?;
+    Ok((input.to_string(), checksum))
+}
+
+/// Calculate the check digit of `base` under each amount of zero padding.
+///
+/// Element `k` of the result is the check digit of `base` with `k` leading
+/// zeros, for `k` from 0 to `max_leading_zeros`. Unlike Luhn, a leading zero
+/// is not neutral in Verhoeff: it is mapped through its position's
+/// permutation, which sends 0 to a non-identity element except in every
+/// eighth position, so padding a base usually changes its check digit.
+/// Fixed-width layouts must therefore compute the check digit over the
+/// padded form. Each extra padding costs one table lookup.
+///
+/// # Returns
+///
+/// * `Ok(Vec<u8>)` - `max_leading_zeros + 1` check digits, unpadded first
+/// * `Err(VerhoeffError)` - If the base is empty or contains non-digits
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{calculate_checksum, checksum_for_paddings};
+///
+/// let digits = checksum_for_paddings("12345", 2)?;
+/// assert_eq!(digits[0], calculate_checksum("12345"));
+/// assert_eq!(digits[2], calculate_checksum("0012345"));
+/// # Ok::<(), verhoeff::VerhoeffError>(())
+/// ```
+#[cfg(feature = "alloc")]
+pub fn checksum_for_paddings(base: &str, max_leading_zeros: usize) -> Result<Vec<u8>> {
+    let mut c = accumulate_ascii(base, 1, 0)?;
+
+    let mut checksums = Vec::with_capacity(max_leading_zeros.saturating_add(1));
+    checksums.push(INV_TABLE[c as usize]);
+    for zeros in 1..=max_leading_zeros {
+        // Each zero becomes the new leftmost digit, one position further out
+        c = accumulate(core::iter::once(0), base.len() + zeros, c);
+        checksums.push(INV_TABLE[c as usize]);
+    }
+    Ok(checksums)
+}
+
+/// Append a Verhoeff checksum digit and group the result for display.
+///
+/// After the check digit is appended, `sep` is inserted every `group_size`
+/// digits counting from the left, so the last group may be shorter. A
+/// `group_size` of 0 means no grouping. The output validates with
+/// [`validate_normalized`], or with [`validate_with_separators`] given
+/// `sep`.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The grouped number including its check digit
+/// * `Err(VerhoeffError::InvalidSeparator)` - If `sep` is an ASCII digit
+/// * `Err(VerhoeffError)` - If the input is empty or contains non-digits
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::append_checksum_grouped;
+///
+/// assert_eq!(append_checksum_grouped("123456789", 4, ' ')?, "1234 5678 90");
+/// assert_eq!(append_checksum_grouped("12345678901", 4, '-')?, "1234-5678-9010");
+/// # Ok::<(), verhoeff::VerhoeffError>(())
+/// ```
+#[cfg(feature = "alloc")]
+pub fn append_checksum_grouped(input: &str, group_size: usize, sep: char) -> Result<String> {
+    if sep.is_ascii_digit() {
+        return Err(VerhoeffError::InvalidSeparator(sep));
+    }
+
+    let full = append_checksum_result(input)?;
+    if group_size == 0 {
+        Ok(full)
+    } else {
+        Ok(group_digits(&full, group_size, sep))
+    }
+}
+
+/// Fill in a pending check digit marked by a trailing placeholder.
+///
+/// `template` is the base number followed by exactly one `placeholder`,
+/// for systems that reserve the check digit position before it is known.
+/// The placeholder is replaced by the checksum of the digits before it, so
+/// `fill_checksum("12345?", '?')` is `append_checksum("12345")`.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The template with the check digit substituted
+/// * `Err(VerhoeffError::InvalidPlaceholderCount)` - Unless `placeholder`
+///   appears exactly once
+/// * `Err(VerhoeffError::InvalidCharacter)` - If the placeholder is not the
+///   last character, or the base contains non-digits
+/// * `Err(VerhoeffError::EmptyInput)` - If the template or its base is empty
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{fill_checksum, VerhoeffError};
+///
+/// assert_eq!(fill_checksum("12345?", '?'), Ok("123451".to_string()));
+/// assert_eq!(
+///     fill_checksum("123?45", '?'),
+///     Err(VerhoeffError::InvalidCharacter { ch: '?', index: 3 })
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn fill_checksum(template: &str, placeholder: char) -> Result<String> {
+    if template.is_empty() {
+        return Err(VerhoeffError::EmptyInput);
+    }
+
+    let count = template.chars().filter(|&ch| ch == placeholder).count();
+    if count != 1 {
+        return Err(VerhoeffError::InvalidPlaceholderCount(count));
+    }
+
+    match template.strip_suffix(placeholder) {
+        Some(base) => append_checksum_result(base),
+        None => {
+            let index = template
+                .chars()
+                .position(|ch| ch == placeholder)
+                .expect("placeholder counted above");
+            Err(VerhoeffError::InvalidCharacter {
+                ch: placeholder,
+                index,
+            })
+        }
+    }
+}
+
+/// Append `n` Verhoeff check digits to a number.
+///
+/// Each successive digit is the checksum of the input plus all digits
+/// appended before it, so `n = 1` is exactly [`append_checksum`]. Every
+/// extra digit makes a random corruption roughly ten times less likely to
+/// go unnoticed, at the cost of one more character; the single-digit and
+/// adjacent-transposition guarantees come from the first digit and are the
+/// same for any `n >= 1`.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The input followed by its `n` check digits
+/// * `Err(VerhoeffError)` - If the input is empty or contains non-digits
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{calculate_checksum_n, validate_n};
+///
+/// let full = calculate_checksum_n("12345", 2).unwrap();
+/// assert_eq!(full, "1234510");
+/// assert_eq!(validate_n(&full, 2), Ok(true));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn calculate_checksum_n(input: &str, n: usize) -> Result<String> {
+    check_digits(input)?;
+
+    let mut full = String::with_capacity(input.len() + n);
+    full.push_str(input);
+    for _ in 0..n {
+        let c = accumulate(digits_rev(&full), 1, 0);
+        full.push(char::from(b'0' + INV_TABLE[c as usize]));
+    }
+    Ok(full)
+}
+
+/// Validate a number ending in `n` check digits produced by
+/// `calculate_checksum_n`.
+///
+/// `n = 1` is equivalent to [`validate_result`]. Nothing is allocated.
+///
+/// # Returns
+///
+/// * `Ok(true)` - If all `n` check digits are correct
+/// * `Ok(false)` - If any check digit is wrong
+/// * `Err(VerhoeffError)` - If the input contains non-digits, or has no
+///   digits before the `n` check digits (`EmptyInput`)
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::validate_n;
+///
+/// assert_eq!(validate_n("1234510", 2), Ok(true));
+/// assert_eq!(validate_n("1234511", 2), Ok(false));
+/// ```
+pub fn validate_n(input: &str, n: usize) -> Result<bool> {
+    check_digits(input)?;
+    if input.len() <= n {
+        return Err(VerhoeffError::EmptyInput);
+    }
+
+    // Every prefix ending in one of the check digits must itself be valid
+    Ok((0..n).all(|i| accumulate(digits_rev(&input[..input.len() - i]), 0, 0) == 0))
+}
+
+/// Prepend a Verhoeff checksum digit to a number.
+///
+/// For schemes that put the check digit first. The digit is the same one
+/// [`append_checksum`] would add; only its placement differs. Use
+/// [`validate_leading`] to check the result.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{prepend_checksum, validate_leading};
+///
+/// let with_checksum = prepend_checksum("12345");
+/// assert_eq!(with_checksum, "112345");
+/// assert!(validate_leading(&with_checksum));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn prepend_checksum(input: &str) -> String {
+    match calculate_checksum_result(input) {
+        Ok(checksum) => format!("{checksum}{input}"),
+        Err(_) => input.to_string(),
+    }
+}
+
+/// Validate a number whose Verhoeff check digit is its *first* character.
+///
+/// `validate_leading("{c}{base}")` is equivalent to
+/// `validate("{base}{c}")`: the check digit keeps permutation row 0, which
+/// it would have in the trailing position, and the base digits keep the
+/// rows `1, 2, ...` counted from the right end of the base. Simply running
+/// [`validate`] on the leading form would shift every base digit by one
+/// position and give a different answer.
+///
+/// # Returns
+///
+/// * `true` if the leading checksum is valid
+/// * `false` if it is invalid or the input is malformed
+pub fn validate_leading(input: &str) -> bool {
+    if check_digits(input).is_err() {
+        return false;
+    }
+
+    let (check, base) = input.split_at(1);
+    let check = check.as_bytes()[0] - b'0';
+
+    // The check digit contributes P_TABLE[0][check] == check first
+    accumulate(digits_rev(base), 1, check) == 0
+}
+
+/// Calculate the check digit under the reversed digit-order convention.
+///
+/// The standard convention, used everywhere else in this crate, weights the
+/// base digits from the right: the rightmost base digit gets permutation
+/// row 1, the next row 2, and so on. Some systems feed digits
+/// most-significant-last and instead weight the base from the *left*.
+///
+/// `calculate_checksum_reversed(base)` therefore equals
+/// [`calculate_checksum`] of `base` written backwards. The two conventions
+/// are not compatible: a check digit from one will generally fail to
+/// validate under the other, so only use these functions to interoperate
+/// with a system known to use the reversed order.
+///
+/// # Returns
+///
+/// * `Ok(u8)` - The check digit
+/// * `Err(VerhoeffError)` - If the input is empty or contains non-digits
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{calculate_checksum, calculate_checksum_reversed};
+///
+/// assert_eq!(calculate_checksum_reversed("12345"), Ok(calculate_checksum("54321")));
+/// assert_ne!(calculate_checksum_reversed("12345"), Ok(calculate_checksum("12345")));
+/// ```
+pub fn calculate_checksum_reversed(input: &str) -> Result<u8> {
+    check_digits(input)?;
+    let c = accumulate(input.bytes().map(|b| b - b'0'), 1, 0);
+    Ok(INV_TABLE[c as usize])
+}
+
+/// Append the check digit under the reversed digit-order convention.
+///
+/// The check digit still goes at the end; only the weighting of the base
+/// digits differs, as described on [`calculate_checksum_reversed`]. Use
+/// [`validate_reversed`] to check the result.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{append_checksum_reversed, validate, validate_reversed};
+///
+/// let full = append_checksum_reversed("12345")?;
+/// assert!(validate_reversed(&full)?);
+/// assert!(!validate(&full));
+/// # Ok::<(), verhoeff::VerhoeffError>(())
+/// ```
+#[cfg(feature = "alloc")]
+pub fn append_checksum_reversed(input: &str) -> Result<String> {
+    let checksum = calculate_checksum_reversed(input)?;
+    Ok(format!("{input}{checksum}"))
+}
+
+/// Validate a number whose trailing check digit was computed under the
+/// reversed digit-order convention of [`calculate_checksum_reversed`].
+///
+/// # Returns
+///
+/// * `Ok(true)` - If the checksum is valid
+/// * `Ok(false)` - If the checksum is invalid
+/// * `Err(VerhoeffError)` - If the input is empty or contains non-digits
+pub fn validate_reversed(input: &str) -> Result<bool> {
+    check_digits(input)?;
+    let (base, check) = input.split_at(input.len() - 1);
+
+    // The check digit keeps row 0, so it seeds the product as is
+    let check = check.as_bytes()[0] - b'0';
+    Ok(accumulate(base.bytes().map(|b| b - b'0'), 1, check) == 0)
+}
+
+/// Split a full number into its base and trailing check digit.
+///
+/// This is purely structural: the check digit is not validated. It is the
+/// inverse of `append_checksum` for any non-empty base.
+///
+/// A single-digit input splits into an empty base and that digit. The empty
+/// base is returned rather than rejected because the split itself is
+/// well-defined; note that it cannot be passed back to
+/// [`calculate_checksum_result`], which rejects empty input.
+///
+/// # Returns
+///
+/// * `Ok((base, check_digit))` - The base substring and the parsed last digit
+/// * `Err(VerhoeffError)` - If the input is empty or contains non-digits
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::split_checksum;
+///
+/// assert_eq!(split_checksum("123451"), Ok(("12345", 1)));
+/// assert_eq!(split_checksum("7"), Ok(("", 7)));
+/// ```
+pub fn split_checksum(input: &str) -> Result<(&str, u8)> {
+    check_digits(input)?;
+
+    // Checked input is all ASCII digits, so the last byte is the check digit
+    let (base, check) = input.split_at(input.len() - 1);
+    Ok((base, check.as_bytes()[0] - b'0'))
+}
+
+/// Validate a base and a separately stored check digit.
+///
+/// The counterpart of [`split_checksum`] for storage that keeps the check
+/// digit in its own column: the check digit is folded in directly, so no
+/// combined string is built and nothing is allocated.
+///
+/// # Arguments
+///
+/// * `base` - The number without its check digit
+/// * `check_digit` - The stored check digit value, 0-9
+///
+/// # Returns
+///
+/// * `Ok(true)` - If `check_digit` is the check digit of `base`
+/// * `Ok(false)` - If it is not
+/// * `Err(VerhoeffError::InvalidDigitValue)` - If `check_digit` is above 9
+/// * `Err(VerhoeffError)` - If the base is empty or contains non-digits
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{validate_split, VerhoeffError};
+///
+/// assert_eq!(validate_split("12345", 1), Ok(true));
+/// assert_eq!(validate_split("12345", 2), Ok(false));
+/// assert_eq!(validate_split("12345", 10), Err(VerhoeffError::InvalidDigitValue(10)));
+/// ```
+pub fn validate_split(base: &str, check_digit: u8) -> Result<bool> {
+    if check_digit > 9 {
+        return Err(VerhoeffError::InvalidDigitValue(check_digit));
+    }
+
+    // The check digit keeps row 0, so it seeds the product as is
+    Ok(accumulate_ascii(base, 1, check_digit)? == 0)
+}
+
+/// The parts of a full number, as returned by [`breakdown`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Breakdown<'a> {
+    /// The number without its check digit; empty for single-digit input
+    pub base: &'a str,
+    /// The trailing check digit as written
+    pub check_digit: u8,
+    /// The whole input, base followed by check digit
+    pub full: &'a str,
+    /// Whether `check_digit` is the correct check digit for `base`
+    pub valid: bool,
+}
+
+/// Split a full number into its parts and validate it in one call.
+///
+/// This combines [`split_checksum`] and [`validate_result`]: a wrong check
+/// digit is reported through `valid` rather than as an error.
+///
+/// # Returns
+///
+/// * `Ok(Breakdown)` - The base, check digit, full input and validity
+/// * `Err(VerhoeffError)` - If the input is empty or contains non-digits
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::breakdown;
+///
+/// let parts = breakdown("123451")?;
+/// assert_eq!(parts.base, "12345");
+/// assert_eq!(parts.check_digit, 1);
+/// assert!(parts.valid);
+///
+/// assert!(!breakdown("123452")?.valid);
+/// # Ok::<(), verhoeff::VerhoeffError>(())
+/// ```
+pub fn breakdown(input: &str) -> Result<Breakdown<'_>> {
+    let (base, check_digit) = split_checksum(input)?;
+    Ok(Breakdown {
+        base,
+        check_digit,
+        full: input,
+        valid: accumulate(digits_rev(input), 0, 0) == 0,
+    })
+}
+
+/// Validate a full number and return its base without the check digit.
+///
+/// Unlike [`validate_result`], a wrong check digit is an error rather than
+/// `Ok(false)`, so malformed input and a bad checksum can be told apart.
+///
+/// The returned base is never empty, so it can always be passed back to
+/// [`calculate_checksum_result`]. A single-digit input is only a check
+/// digit with nothing to strip it from, so it is rejected with
+/// `EmptyInput` before its checksum is considered, even though
+/// [`validate`] accepts `"0"`. Use [`split_checksum`] to take such an
+/// input apart, or [`validate_checked`] to only check it.
+///
+/// # Returns
+///
+/// * `Ok(base)` - The input minus its trailing check digit
+/// * `Err(VerhoeffError::ChecksumMismatch)` - If the check digit is wrong
+/// * `Err(VerhoeffError::EmptyInput)` - If the input is empty or a single
+///   digit
+/// * `Err(VerhoeffError)` - If the input contains non-digits
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{validate_and_strip, VerhoeffError};
+///
+/// assert_eq!(validate_and_strip("123451"), Ok("12345"));
+/// assert_eq!(
+///     validate_and_strip("123454"),
+///     Err(VerhoeffError::ChecksumMismatch { expected: 1, found: 4 })
+/// );
+/// ```
+pub fn validate_and_strip(input: &str) -> Result<&str> {
+    let (base, found) = split_checksum(input)?;
+    if base.is_empty() {
+        return Err(VerhoeffError::EmptyInput);
+    }
+    check_split(base, found).map(|()| base)
+}
+
+/// Checks that `found` is the check digit of the checked digits `base`
+fn check_split(base: &str, found: u8) -> Result<()> {
+    let expected = INV_TABLE[accumulate(digits_rev(base), 1, 0) as usize];
+    if expected == found {
+        Ok(())
+    } else {
+        Err(VerhoeffError::ChecksumMismatch { expected, found })
+    }
+}
+
+/// Validate a number, explaining any failure.
+///
+/// This is the strict counterpart of [`validate`]: instead of `false`, a wrong
+/// check digit is reported as `VerhoeffError::ChecksumMismatch` carrying the
+/// check digit that was expected and the one that was found. Malformed input
+/// yields the usual character or empty-input errors. Like [`validate`], it
+/// accepts the single-digit number `"0"`.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{validate_checked, VerhoeffError};
+///
+/// assert_eq!(validate_checked("123451"), Ok(()));
+/// assert_eq!(
+///     validate_checked("123454"),
+///     Err(VerhoeffError::ChecksumMismatch { expected: 1, found: 4 })
+/// );
+/// ```
+pub fn validate_checked(input: &str) -> Result<()> {
+    let (base, found) = split_checksum(input)?;
+    check_split(base, found)
+}
+
+/// Validate a number and parse its base, without the check digit, as a
+/// `u64`.
+///
+/// Saves parsing the digits a second time after validation. The steps run
+/// in order: malformed input is an error, a wrong check digit gives
+/// `Ok(None)`, and only a valid number is parsed. If its base exceeds
+/// `u64::MAX` the result is `VerhoeffError::IntegerOverflow`; leading zeros
+/// never overflow. The base of a single-digit number is empty and parses
+/// as 0.
+///
+/// # Returns
+///
+/// * `Ok(Some(u64))` - The base of a valid number
+/// * `Ok(None)` - If the checksum is invalid
+/// * `Err(VerhoeffError::IntegerOverflow)` - If the base does not fit
+/// * `Err(VerhoeffError)` - If the input is empty or contains non-digits
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::validate_to_u64;
+///
+/// assert_eq!(validate_to_u64("123451"), Ok(Some(12345)));
+/// assert_eq!(validate_to_u64("123452"), Ok(None));
+/// assert!(validate_to_u64("12a451").is_err());
+/// ```
+pub fn validate_to_u64(input: &str) -> Result<Option<u64>> {
+    let base = validate_to_u128(input).map_err(|e| match e {
+        VerhoeffError::IntegerOverflow { .. } => VerhoeffError::IntegerOverflow { bits: 64 },
+        e => e,
+    })?;
+    base.map(|value| u64::try_from(value).map_err(|_| VerhoeffError::IntegerOverflow { bits: 64 }))
+        .transpose()
+}
+
+/// Validate a number and parse its base as a `u128`, for IDs longer than
+/// [`validate_to_u64`] supports.
+///
+/// Follows the same rules as [`validate_to_u64`], overflowing only past
+/// `u128::MAX`.
+pub fn validate_to_u128(input: &str) -> Result<Option<u128>> {
+    if !validate_result(input)? {
+        return Ok(None);
+    }
+
+    let base = &input.as_bytes()[..input.len() - 1];
+    base.iter()
+        .try_fold(0u128, |value, &b| {
+            value.checked_mul(10)?.checked_add(u128::from(b - b'0'))
+        })
+        .map(Some)
+        .ok_or(VerhoeffError::IntegerOverflow { bits: 128 })
+}
+
+/// Replace the trailing check digit of a number with the correct one.
+///
+/// All but the last digit are trusted as the base, and the last digit is
+/// overwritten with the check digit computed over them, so a valid input is
+/// returned unchanged. Unlike [`append_checksum_result`], which adds a
+/// digit, this keeps the length the same. A single digit has an empty base,
+/// whose check digit is 0.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The number with a correct check digit
+/// * `Err(VerhoeffError)` - If the input is empty or contains non-digits
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::repair_checksum;
+///
+/// assert_eq!(repair_checksum("123459")?, "123451");
+/// assert_eq!(repair_checksum("123451")?, "123451");
+/// # Ok::<(), verhoeff::VerhoeffError>(())
+/// ```
+#[cfg(feature = "alloc")]
+pub fn repair_checksum(full: &str) -> Result<String> {
+    match validate_checked(full) {
+        Ok(()) => Ok(full.to_string()),
+        Err(VerhoeffError::ChecksumMismatch { expected, .. }) => {
+            Ok(format!("{}{expected}", &full[..full.len() - 1]))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Whether `repair_checksum` would change the number.
+///
+/// # Returns
+///
+/// * `Ok(true)` - If the trailing check digit is wrong
+/// * `Ok(false)` - If the number is already valid
+/// * `Err(VerhoeffError)` - If the input is empty or contains non-digits
+pub fn needs_repair(full: &str) -> Result<bool> {
+    match validate_checked(full) {
+        Ok(()) => Ok(false),
+        Err(VerhoeffError::ChecksumMismatch { .. }) => Ok(true),
+        Err(e) => Err(e),
+    }
+}
+
+/// Why [`diagnose`] rejected a number.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InvalidReason {
+    /// The input is well-formed, but its trailing check digit is wrong
+    WrongCheckDigit {
+        /// The check digit computed over the base
+        expected: u8,
+        /// The check digit present in the input
+        found: u8,
+    },
+    /// The input is empty or contains non-digits
+    Malformed(VerhoeffError),
+}
+
+impl fmt::Display for InvalidReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InvalidReason::WrongCheckDigit { expected, found } => {
+                write!(f, "Wrong check digit - expected {expected}, found {found}")
+            }
+            InvalidReason::Malformed(err) => write!(f, "Malformed input - {err}"),
+        }
+    }
+}
+
+/// Validate a number and classify why it fails.
+///
+/// Separates a wrong check digit, which usually means a typo in the digits,
+/// from malformed input that is not a number at all, so callers need not
+/// match on individual [`VerhoeffError`] variants.
+///
+/// # Returns
+///
+/// * `Ok(())` - If the number is valid
+/// * `Err(InvalidReason::WrongCheckDigit)` - If only the checksum is wrong
+/// * `Err(InvalidReason::Malformed)` - If the input is empty or has
+///   non-digits
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{diagnose, InvalidReason};
+///
+/// assert_eq!(diagnose("123451"), Ok(()));
+/// assert_eq!(
+///     diagnose("123454"),
+///     Err(InvalidReason::WrongCheckDigit { expected: 1, found: 4 })
+/// );
+/// assert!(matches!(diagnose("12-45"), Err(InvalidReason::Malformed(_))));
+/// ```
+pub fn diagnose(input: &str) -> Result<(), InvalidReason> {
+    validate_checked(input).map_err(|e| match e {
+        VerhoeffError::ChecksumMismatch { expected, found } => {
+            InvalidReason::WrongCheckDigit { expected, found }
+        }
+        e => InvalidReason::Malformed(e),
+    })
+}
+
+/// Validate a number whose check digit sits at `check_index` rather than at
+/// the end.
+///
+/// The check digit is the one [`calculate_checksum`] would compute over the
+/// other digits in their original order. Permutation rows are therefore
+/// assigned exactly as if the check digit had been moved to the end: the
+/// non-check digit `j` places from the right of the remaining digits uses
+/// row `(j + 1) % 8`. With `check_index == input.len() - 1` this is plain
+/// [`validate_result`]. Nothing is allocated.
+///
+/// # Returns
+///
+/// * `Ok(true)` - If the embedded check digit is correct
+/// * `Ok(false)` - If it is wrong
+/// * `Err(VerhoeffError::IndexOutOfBounds)` - If `check_index` is past the
+///   last digit
+/// * `Err(VerhoeffError)` - If the input is empty or contains non-digits
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::validate_at;
+///
+/// // "12345" with its check digit 1 embedded at index 2
+/// assert_eq!(validate_at("121345", 2), Ok(true));
+/// assert_eq!(validate_at("123451", 5), Ok(true));
+/// ```
+pub fn validate_at(input: &str, check_index: usize) -> Result<bool> {
+    check_digits(input)?;
+    if check_index >= input.len() {
+        return Err(VerhoeffError::IndexOutOfBounds {
+            index: check_index,
+            len: input.len(),
+        });
+    }
+
+    let found = input.as_bytes()[check_index] - b'0';
+    let others = digits_rev(&input[check_index + 1..]).chain(digits_rev(&input[..check_index]));
+    Ok(INV_TABLE[accumulate(others, 1, 0) as usize] == found)
+}
+
+/// Insert the check digit of `base` at `check_index`.
+///
+/// The inverse of [`validate_at`]: the digit is computed over `base` as
+/// [`calculate_checksum`] would, then inserted before the digit currently at
+/// `check_index`. `check_index == base.len()` appends, like
+/// [`append_checksum`].
+///
+/// # Returns
+///
+/// * `Ok(String)` - `base` with its check digit inserted
+/// * `Err(VerhoeffError::IndexOutOfBounds)` - If `check_index > base.len()`
+/// * `Err(VerhoeffError)` - If `base` is empty or contains non-digits
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::insert_checksum;
+///
+/// assert_eq!(insert_checksum("12345", 2).unwrap(), "121345");
+/// assert_eq!(insert_checksum("12345", 0).unwrap(), "112345");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn insert_checksum(base: &str, check_index: usize) -> Result<String> {
+    let check = calculate_checksum_result(base)?;
+    if check_index > base.len() {
+        return Err(VerhoeffError::IndexOutOfBounds {
+            index: check_index,
+            len: base.len(),
+        });
+    }
+
+    let mut full = String::with_capacity(base.len() + 1);
+    full.push_str(&base[..check_index]);
+    full.push(char::from(b'0' + check));
+    full.push_str(&base[check_index..]);
+    Ok(full)
+}
+
+/// Validate a number that must have an exact total length.
+///
+/// The length is counted in characters and includes the check digit. It is
+/// checked before the content, so a wrong-length input reports
+/// `InvalidLength` even if it also contains non-digits.
+///
+/// # Arguments
+///
+/// * `input` - The full number, including its check digit
+/// * `total_len` - The required number of characters
+///
+/// # Returns
+///
+/// * `Ok(true)` - If the length is right and the checksum is valid
+/// * `Ok(false)` - If the length is right but the checksum is invalid
+/// * `Err(VerhoeffError::InvalidLength)` - If the length is wrong
+/// * `Err(VerhoeffError)` - If the input is empty or contains non-digits
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{validate_fixed_length, VerhoeffError};
+///
+/// assert_eq!(validate_fixed_length("8473643095", 10), Ok(true));
+/// assert_eq!(
+///     validate_fixed_length("123451", 10),
+///     Err(VerhoeffError::InvalidLength { expected: 10, got: 6 })
+/// );
+/// ```
+pub fn validate_fixed_length(input: &str, total_len: usize) -> Result<bool> {
+    // Count characters, so multibyte input gets a character error
+    let got = input.chars().count();
+    if got != total_len {
+        return Err(VerhoeffError::InvalidLength {
+            expected: total_len,
+            got,
+        });
+    }
+
+    validate_result(input)
+}
+
+/// Validate a number whose leading digits declare its own length.
+///
+/// The first `prefix_digits` digits are read as a decimal length `L`, and
+/// the input must be exactly those prefix digits, then `L` payload digits,
+/// then the check digit. The check digit covers everything before it,
+/// prefix included, so this is [`validate_result`] plus the length check.
+///
+/// # Arguments
+///
+/// * `input` - The full number: length prefix, payload and check digit
+/// * `prefix_digits` - How many leading digits hold the declared length
+///
+/// # Returns
+///
+/// * `Ok(true)` - If the length matches and the checksum is valid
+/// * `Ok(false)` - If the length matches but the checksum is invalid
+/// * `Err(VerhoeffError::LengthPrefixMismatch)` - If the number of payload
+///   digits is not the declared length
+/// * `Err(VerhoeffError::InvalidLength)` - If the input is too short to
+///   hold the prefix and a check digit
+/// * `Err(VerhoeffError)` - If the input is empty or contains non-digits
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{validate_length_prefixed, VerhoeffError};
+///
+/// // "05" declares five payload digits, "12345", followed by check digit 6
+/// assert_eq!(validate_length_prefixed("05123456", 2), Ok(true));
+/// assert_eq!(
+///     validate_length_prefixed("06123452", 2),
+///     Err(VerhoeffError::LengthPrefixMismatch { declared: 6, got: 5 })
+/// );
+/// ```
+pub fn validate_length_prefixed(input: &str, prefix_digits: usize) -> Result<bool> {
+    check_digits(input)?;
+    if input.len() <= prefix_digits {
+        return Err(VerhoeffError::InvalidLength {
+            expected: prefix_digits + 1,
+            got: input.len(),
+        });
+    }
+
+    // Saturate rather than overflow: no input is long enough to match
+    let declared = input[..prefix_digits].bytes().fold(0usize, |n, b| {
+        n.saturating_mul(10).saturating_add(usize::from(b - b'0'))
+    });
+    let got = input.len() - prefix_digits - 1;
+    if got != declared {
+        return Err(VerhoeffError::LengthPrefixMismatch { declared, got });
+    }
+
+    Ok(accumulate(digits_rev(input), 0, 0) == 0)
+}
+
+/// Validate several check-digit fields of a fixed-width record in one call.
+///
+/// Each `(start, len)` in `field_specs` names a field by its byte offset
+/// and length within `record`, which for the usual ASCII records are also
+/// character positions. Every field is validated independently, as by
+/// [`validate`], and the index of the first one that fails is returned. A
+/// field that is malformed, for example because it holds a space or is
+/// empty, counts as failing.
+///
+/// All specs are checked against the record length before any field is
+/// validated, so a wrong layout is reported whatever the data.
+///
+/// # Returns
+///
+/// * `Ok(None)` - If every field validates
+/// * `Ok(Some(field_index))` - The index into `field_specs` of the first
+///   failing field
+/// * `Err(VerhoeffError::IndexOutOfBounds)` - If a field extends past the
+///   end of the record; `index` is the field's last byte
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::validate_fields;
+///
+/// // Columns 0-5 and 7-10 hold two independently checked numbers
+/// let fields = [(0, 6), (7, 4)];
+/// assert_eq!(validate_fields("123451 2363", &fields), Ok(None));
+/// assert_eq!(validate_fields("123451 2364", &fields), Ok(Some(1)));
+/// assert!(validate_fields("123451 236", &fields).is_err());
+/// ```
+pub fn validate_fields(record: &str, field_specs: &[(usize, usize)]) -> Result<Option<usize>> {
+    for &(start, len) in field_specs {
+        if start.saturating_add(len) > record.len() {
+            return Err(VerhoeffError::IndexOutOfBounds {
+                index: start.saturating_add(len.saturating_sub(1)),
+                len: record.len(),
+            });
+        }
+    }
+
+    Ok(field_specs
+        .iter()
+        .position(|&(start, len)| !record.get(start..start + len).is_some_and(validate)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_checksum() {
+        // Test cases from known Verhoeff implementations
+        assert_eq!(calculate_checksum("236"), 3);
+        assert_eq!(calculate_checksum("12345"), 1);
+        assert_eq!(calculate_checksum("142857"), 0);
+    }
+
+    #[test]
+    fn test_validate() {
+        // Valid checksums
+        assert!(validate("2363"));
+        assert!(validate("123451"));
+        assert!(validate("1428570"));
+
+        // Invalid checksums
+        assert!(!validate("2364"));
+        assert!(!validate("123450"));
+        assert!(!validate("1428571"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_append_checksum() {
+        assert_eq!(append_checksum("236"), "2363");
+        assert_eq!(append_checksum("12345"), "123451");
+        assert_eq!(append_checksum("142857"), "1428570");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_prepend_checksum_round_trip() {
+        for base in ["236", "12345", "142857", "8473643095", "0", "1234567890123"] {
+            let leading = prepend_checksum(base);
+            let trailing = append_checksum(base);
+
+            assert!(validate_leading(&leading), "'{leading}' should validate");
+            assert_eq!(&leading[1..], base);
+            assert_eq!(leading[..1], trailing[trailing.len() - 1..]);
+        }
+
+        // The leading form is not valid under the trailing convention
+        assert!(!validate("112345"));
+        assert!(!validate_leading("123451"));
+    }
+
+    #[test]
+    fn test_validate_leading() {
+        assert!(validate_leading("3236"));
+        assert!(!validate_leading("4236"));
+        assert!(validate_leading("0"));
+        assert!(!validate_leading(""));
+        assert!(!validate_leading("3a36"));
+    }
+
+    #[test]
+    fn test_array_api_matches_string_api() {
+        fn values<const N: usize>(s: &str) -> [u8; N] {
+            core::array::from_fn(|i| s.as_bytes()[i] - b'0')
+        }
+
+        assert_eq!(calculate_checksum_array(&values::<3>("236")), 3);
+        assert_eq!(calculate_checksum_array(&values::<11>("12345678901")), 0);
+        assert_eq!(
+            calculate_checksum_array(&values::<15>("123456789012345")),
+            calculate_checksum("123456789012345")
+        );
+
+        assert!(validate_array(&values::<4>("2363")));
+        assert!(!validate_array(&values::<4>("2364")));
+        assert_eq!(validate_array(&[0]), validate("0"));
+        assert_eq!(validate_array(&[7]), validate("7"));
+        assert!(validate_array(&values::<12>("123456789010")));
+        assert!(!validate_array(&values::<12>("123456789013")));
+        assert!(validate_array(&values::<16>("1234567890123455")));
+
+        assert_eq!(calculate_checksum_array(&[]), 0);
+        assert!(!validate_array(&[]));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "digit value out of range")]
+    fn test_array_api_debug_contract() {
+        validate_array(&[1, 2, 10]);
+    }
+
+    #[test]
+    fn test_validate_ascii_unchecked_matches_validate() {
+        for input in ["123456789010", "123456789013", "2363", "0", "5", "142857"] {
+            assert_eq!(
+                validate_ascii_unchecked(input.as_bytes()),
+                validate(input),
+                "{input}"
+            );
+        }
+        assert!(!validate_ascii_unchecked(b""));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "non-digit input")]
+    fn test_validate_ascii_unchecked_debug_contract() {
+        validate_ascii_unchecked(b"12a4");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_append_checksum_result() {
+        assert_eq!(
+            append_checksum_result("12345678901"),
+            Ok("123456789010".into())
+        );
+        assert_eq!(
+            append_checksum_result("12a45"),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 2 })
+        );
+        assert_eq!(append_checksum_result(""), Err(VerhoeffError::EmptyInput));
+
+        // The infallible wrapper echoes the input instead
+        assert_eq!(append_checksum("12a45"), "12a45");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_append_checksum_grouped() {
+        assert_eq!(
+            append_checksum_grouped("123456789", 4, ' '),
+            Ok("1234 5678 90".into())
+        );
+        assert_eq!(
+            append_checksum_grouped("12345678901", 4, ' '),
+            Ok("1234 5678 9010".into())
+        );
+        assert_eq!(append_checksum_grouped("236", 4, ' '), Ok("2363".into()));
+        assert_eq!(
+            append_checksum_grouped("12345", 2, '·'),
+            Ok("12·34·51".into())
+        );
+
+        // Group size 0 disables grouping
+        assert_eq!(
+            append_checksum_grouped("12345", 0, ' '),
+            Ok("123451".into())
+        );
+
+        for base in ["1", "236", "8473643095", "1234567890123"] {
+            let grouped = append_checksum_grouped(base, 3, '-').unwrap();
+            assert_eq!(validate_normalized(&grouped), Ok(true), "{grouped}");
+            assert_eq!(validate_with_separators(&grouped, &['-']), Ok(true));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_append_checksum_grouped_errors() {
+        assert_eq!(
+            append_checksum_grouped("12345", 4, '0'),
+            Err(VerhoeffError::InvalidSeparator('0'))
+        );
+        assert_eq!(
+            append_checksum_grouped("12a45", 4, ' '),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 2 })
+        );
+        assert_eq!(
+            append_checksum_grouped("", 4, ' '),
+            Err(VerhoeffError::EmptyInput)
+        );
+    }
+
+    #[test]
+    fn test_breakdown() {
+        assert_eq!(
+            breakdown("123456789010"),
+            Ok(Breakdown {
+                base: "12345678901",
+                check_digit: 0,
+                full: "123456789010",
+                valid: true,
+            })
+        );
+        assert_eq!(
+            breakdown("123456789013"),
+            Ok(Breakdown {
+                base: "12345678901",
+                check_digit: 3,
+                full: "123456789013",
+                valid: false,
+            })
+        );
+        assert_eq!(
+            breakdown("0"),
+            Ok(Breakdown {
+                base: "",
+                check_digit: 0,
+                full: "0",
+                valid: true,
+            })
+        );
+
+        assert_eq!(breakdown(""), Err(VerhoeffError::EmptyInput));
+        assert_eq!(
+            breakdown("1234a"),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 4 })
+        );
+    }
+
+    #[test]
+    fn test_validate_length_prefixed() {
+        for (payload, prefix_digits) in [("12345", 2), ("8473643095", 2), ("", 1), ("236", 3)] {
+            let body = format!("{:0width$}{payload}", payload.len(), width = prefix_digits);
+            let full = format!("{body}{}", calculate_checksum(&body));
+            assert_eq!(
+                validate_length_prefixed(&full, prefix_digits),
+                Ok(true),
+                "{full}"
+            );
+        }
+
+        // Right length, wrong check digit
+        assert_eq!(validate_length_prefixed("05123457", 2), Ok(false));
+    }
+
+    #[test]
+    fn test_validate_length_prefixed_lying_prefix() {
+        // Declares six payload digits but carries five, checksum valid
+        assert!(validate("06123452"));
+        assert_eq!(
+            validate_length_prefixed("06123452", 2),
+            Err(VerhoeffError::LengthPrefixMismatch {
+                declared: 6,
+                got: 5
+            })
+        );
+        assert_eq!(
+            VerhoeffError::LengthPrefixMismatch {
+                declared: 6,
+                got: 5
+            }
+            .to_string(),
+            "Length prefix declares 6 digits, got 5"
+        );
+
+        // A huge declared length saturates instead of overflowing
+        let huge = "9".repeat(40) + "0";
+        assert!(matches!(
+            validate_length_prefixed(&huge, 40),
+            Err(VerhoeffError::LengthPrefixMismatch { got: 0, .. })
+        ));
+
+        assert_eq!(
+            validate_length_prefixed("05", 2),
+            Err(VerhoeffError::InvalidLength {
+                expected: 3,
+                got: 2
+            })
+        );
+        assert_eq!(
+            validate_length_prefixed("05a23456", 2),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 2 })
+        );
+    }
+
+    #[test]
+    fn test_validate_fields() {
+        // Account, branch and customer numbers in fixed columns
+        let record = "123456789010|2363|8473643095";
+        let fields = [(0, 12), (13, 4), (18, 10)];
+        assert_eq!(validate_fields(record, &fields), Ok(None));
+
+        let record = "123456789010|2364|8473643095";
+        assert_eq!(validate_fields(record, &fields), Ok(Some(1)));
+
+        // The first failing field is reported when several fail
+        let record = "123456789013|2364|8473643095";
+        assert_eq!(validate_fields(record, &fields), Ok(Some(0)));
+
+        // Malformed fields fail rather than error
+        let record = "123456789010|23 3|8473643095";
+        assert_eq!(validate_fields(record, &fields), Ok(Some(1)));
+        assert_eq!(validate_fields(record, &[(12, 0)]), Ok(Some(0)));
+
+        assert_eq!(validate_fields(record, &[]), Ok(None));
+    }
+
+    #[test]
+    fn test_validate_fields_out_of_range() {
+        let record = "123456789010|2363";
+        assert_eq!(
+            validate_fields(record, &[(13, 4), (13, 5)]),
+            Err(VerhoeffError::IndexOutOfBounds { index: 17, len: 17 })
+        );
+
+        // Checked before any field is validated
+        assert_eq!(
+            validate_fields(record, &[(0, 4), (20, 1)]),
+            Err(VerhoeffError::IndexOutOfBounds { index: 20, len: 17 })
+        );
+        assert!(validate_fields(record, &[(usize::MAX, 2)]).is_err());
+
+        // Fields need not be on character boundaries to be rejected safely
+        assert_eq!(validate_fields("१23", &[(1, 2)]), Ok(Some(0)));
+    }
+
+    #[test]
+    fn test_validate_optional() {
+        // Absent
+        assert_eq!(validate_optional(""), Ok(None));
+        assert_eq!(validate_optional("   "), Ok(None));
+        assert_eq!(validate_optional("\t\r\n"), Ok(None));
+
+        // Present, with or without surrounding whitespace
+        assert_eq!(validate_optional("123456789010"), Ok(Some(true)));
+        assert_eq!(validate_optional("  123456789010\n"), Ok(Some(true)));
+        assert_eq!(validate_optional("123456789013"), Ok(Some(false)));
+
+        // Malformed, with indices into the original input
+        assert_eq!(
+            validate_optional("12a45"),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 2 })
+        );
+        assert_eq!(
+            validate_optional("  1234 5678"),
+            Err(VerhoeffError::InvalidCharacter { ch: ' ', index: 6 })
+        );
+    }
+
+    #[test]
+    fn test_verhoeff_step_reproduces_one_shot() {
+        for base in ["0", "236", "12345", "8473643095", "123456789012345678"] {
+            let mut c = 0;
+            for (i, b) in base.bytes().rev().enumerate() {
+                c = verhoeff_step(c, b - b'0', i + 1);
+            }
+            let checksum = verhoeff_inverse(c);
+            assert_eq!(checksum, calculate_checksum(base), "{base}");
+
+            // Folding the check digit in at position 0 first validates
+            let mut c = verhoeff_step(0, checksum, 0);
+            for (i, b) in base.bytes().rev().enumerate() {
+                c = verhoeff_step(c, b - b'0', i + 1);
+            }
+            assert_eq!(c, 0, "{base}");
+        }
+    }
+
+    #[test]
+    fn test_verhoeff_step_matches_tables() {
+        for position in 0..16 {
+            for c in 0..10u8 {
+                for digit in 0..10u8 {
+                    let expected =
+                        D_TABLE[c as usize][P_TABLE[position % 8][digit as usize] as usize];
+                    assert_eq!(verhoeff_step(c, digit, position), expected);
+                }
+            }
+        }
+        for c in 0..10u8 {
+            assert_eq!(D_TABLE[c as usize][verhoeff_inverse(c) as usize], 0);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_checksum_and_base() {
+        assert_eq!(checksum_and_base("12345"), Ok(("12345".to_string(), 1)));
+        for base in ["0", "236", "8473643095", "12345678901"] {
+            let (stored, checksum) = checksum_and_base(base).unwrap();
+            assert_eq!(stored, base);
+            assert_eq!(format!("{stored}{checksum}"), append_checksum(base));
+        }
+
+        assert_eq!(checksum_and_base(""), Err(VerhoeffError::EmptyInput));
+        assert_eq!(
+            checksum_and_base("12a45"),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 2 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_checksum_for_paddings() {
+        for base in ["1", "12345", "8473643095", "12345678901"] {
+            let digits = checksum_for_paddings(base, 10).unwrap();
+            assert_eq!(digits.len(), 11);
+            for (zeros, &digit) in digits.iter().enumerate() {
+                let padded = format!("{}{base}", "0".repeat(zeros));
+                assert_eq!(digit, calculate_checksum(&padded), "{padded}");
+            }
+        }
+
+        // Padding shifts the check digit, except when the new zero lands in
+        // position 8 (counting the check digit as 0), whose row is the identity
+        let digits = checksum_for_paddings("12345", 3).unwrap();
+        assert_eq!(digits, [1, 4, 8, 8]);
+
+        assert_eq!(checksum_for_paddings("12345", 0), Ok(vec![1]));
+        assert_eq!(checksum_for_paddings("", 3), Err(VerhoeffError::EmptyInput));
+        assert_eq!(
+            checksum_for_paddings("12a", 3),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 2 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_fill_checksum() {
+        assert_eq!(fill_checksum("12345?", '?'), Ok(append_checksum("12345")));
+        for base in ["1", "236", "8473643095", "12345678901"] {
+            let template = format!("{base}_");
+            assert_eq!(fill_checksum(&template, '_'), Ok(append_checksum(base)));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_fill_checksum_errors() {
+        assert_eq!(
+            fill_checksum("12345", '?'),
+            Err(VerhoeffError::InvalidPlaceholderCount(0))
+        );
+        assert_eq!(
+            fill_checksum("12?45?", '?'),
+            Err(VerhoeffError::InvalidPlaceholderCount(2))
+        );
+        assert_eq!(
+            fill_checksum("?12345", '?'),
+            Err(VerhoeffError::InvalidCharacter { ch: '?', index: 0 })
+        );
+        assert_eq!(
+            fill_checksum("12a45?", '?'),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 2 })
+        );
+        assert_eq!(fill_checksum("", '?'), Err(VerhoeffError::EmptyInput));
+        assert_eq!(fill_checksum("?", '?'), Err(VerhoeffError::EmptyInput));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_repair_checksum() {
+        for base in ["236", "12345", "12345678901", "8473643095"] {
+            let correct = calculate_checksum(base);
+            for wrong in (0..10).filter(|&d| d != correct) {
+                let broken = format!("{base}{wrong}");
+                assert_eq!(needs_repair(&broken), Ok(true), "{broken}");
+
+                let repaired = repair_checksum(&broken).unwrap();
+                assert!(validate(&repaired), "{repaired}");
+                assert_eq!(repaired, append_checksum(base));
+            }
+        }
+
+        // Valid input is left as is
+        assert_eq!(repair_checksum("123456789010"), Ok("123456789010".into()));
+        assert_eq!(needs_repair("123456789010"), Ok(false));
+
+        // A lone digit has an empty base
+        assert_eq!(repair_checksum("7"), Ok("0".into()));
+        assert_eq!(needs_repair("0"), Ok(false));
+
+        assert_eq!(repair_checksum(""), Err(VerhoeffError::EmptyInput));
+        assert_eq!(
+            needs_repair("12a4"),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 2 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_validate_to_u64_boundary() {
+        let max = u64::MAX.to_string();
+        assert_eq!(validate_to_u64(&append_checksum(&max)), Ok(Some(u64::MAX)));
+
+        let past = (u128::from(u64::MAX) + 1).to_string();
+        let full = append_checksum(&past);
+        assert_eq!(
+            validate_to_u64(&full),
+            Err(VerhoeffError::IntegerOverflow { bits: 64 })
+        );
+        assert_eq!(validate_to_u128(&full), Ok(Some(u128::from(u64::MAX) + 1)));
+
+        // Leading zeros do not count towards overflow
+        let padded = format!("0000000000{max}");
+        assert_eq!(
+            validate_to_u64(&append_checksum(&padded)),
+            Ok(Some(u64::MAX))
+        );
+
+        // An invalid checksum is reported before any overflow
+        let wrong = (calculate_checksum(&past) + 1) % 10;
+        assert_eq!(validate_to_u64(&format!("{past}{wrong}")), Ok(None));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_validate_to_u128() {
+        let max = u128::MAX.to_string();
+        assert_eq!(
+            validate_to_u128(&append_checksum(&max)),
+            Ok(Some(u128::MAX))
+        );
+        assert_eq!(
+            validate_to_u128(&append_checksum(&format!("{max}0"))),
+            Err(VerhoeffError::IntegerOverflow { bits: 128 })
+        );
+
+        assert_eq!(validate_to_u128("0"), Ok(Some(0)));
+        assert_eq!(validate_to_u128("7"), Ok(None));
+        assert_eq!(validate_to_u128(""), Err(VerhoeffError::EmptyInput));
+    }
+
+    #[test]
+    fn test_diagnose() {
+        assert_eq!(diagnose("123456789010"), Ok(()));
+        assert_eq!(diagnose("0"), Ok(()));
+
+        assert_eq!(
+            diagnose("123456789013"),
+            Err(InvalidReason::WrongCheckDigit {
+                expected: 0,
+                found: 3
+            })
+        );
+        assert_eq!(
+            diagnose("12b45"),
+            Err(InvalidReason::Malformed(VerhoeffError::InvalidCharacter {
+                ch: 'b',
+                index: 2
+            }))
+        );
+        assert_eq!(
+            diagnose(""),
+            Err(InvalidReason::Malformed(VerhoeffError::EmptyInput))
+        );
+    }
+
+    #[test]
+    fn test_invalid_reason_display() {
+        assert_eq!(
+            diagnose("2364").unwrap_err().to_string(),
+            "Wrong check digit - expected 3, found 4"
+        );
+        assert_eq!(
+            diagnose("").unwrap_err().to_string(),
+            "Malformed input - Input cannot be empty"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_reversed_convention() {
+        assert_eq!(calculate_checksum_reversed("12345"), Ok(7));
+        assert_eq!(calculate_checksum("12345"), 1);
+        assert_eq!(
+            calculate_checksum_reversed("12345"),
+            Ok(calculate_checksum("54321"))
+        );
+
+        for base in ["236", "12345", "142857", "8473643095", "0", "1234567890123"] {
+            let full = append_checksum_reversed(base).unwrap();
+            assert_eq!(&full[..base.len()], base);
+            assert_eq!(validate_reversed(&full), Ok(true), "{full}");
+
+            // Matches the standard convention on the mirrored base
+            let mirrored: String = base.chars().rev().collect();
+            assert_eq!(
+                full.as_bytes()[base.len()] - b'0',
+                calculate_checksum(&mirrored)
+            );
+        }
+
+        // The standard check digit does not validate under the reversed one
+        assert_eq!(validate_reversed("123451"), Ok(false));
+        assert_eq!(validate_reversed("123457"), Ok(true));
+        assert_eq!(validate_reversed("0"), validate_result("0"));
+
+        assert_eq!(validate_reversed(""), Err(VerhoeffError::EmptyInput));
+        assert_eq!(
+            append_checksum_reversed("12a"),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 2 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_split_checksum() {
+        let full = append_checksum("12345");
+        assert_eq!(split_checksum(&full), Ok(("12345", 1)));
+        assert_eq!(split_checksum("123456789010"), Ok(("12345678901", 0)));
+
+        // Structural only: a wrong check digit still splits
+        assert_eq!(split_checksum("2364"), Ok(("236", 4)));
+
+        // Single digit: empty base
+        assert_eq!(split_checksum("7"), Ok(("", 7)));
+
+        assert_eq!(split_checksum(""), Err(VerhoeffError::EmptyInput));
+        assert_eq!(
+            split_checksum("1234x"),
+            Err(VerhoeffError::InvalidCharacter { ch: 'x', index: 4 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_checksum_n_round_trip() {
+        assert_eq!(
+            calculate_checksum_n("12345", 1).unwrap(),
+            append_checksum("12345")
+        );
+        assert_eq!(validate_n("123451", 1), validate_result("123451"));
+
+        for n in [2, 3] {
+            for base in ["0", "12345", "123456789012", "9999999999"] {
+                let full = calculate_checksum_n(base, n).unwrap();
+                assert_eq!(full.len(), base.len() + n);
+                assert_eq!(validate_n(&full, n), Ok(true), "{full}");
+
+                // Every single-digit corruption is caught
+                for pos in 0..full.len() {
+                    for digit in b'0'..=b'9' {
+                        let mut bytes = full.clone().into_bytes();
+                        if bytes[pos] == digit {
+                            continue;
+                        }
+                        bytes[pos] = digit;
+                        let corrupted = String::from_utf8(bytes).unwrap();
+                        assert_eq!(validate_n(&corrupted, n), Ok(false), "{corrupted}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_n_errors() {
+        assert_eq!(validate_n("12", 2), Err(VerhoeffError::EmptyInput));
+        assert_eq!(validate_n("", 1), Err(VerhoeffError::EmptyInput));
+        assert_eq!(
+            validate_n("12a4510", 2),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 2 })
+        );
+    }
+
+    #[test]
+    fn test_validate_split() {
+        for full in ["123451", "2363", "123456789010", "8473643095", "04"] {
+            let (base, check_digit) = split_checksum(full).unwrap();
+            assert_eq!(validate_split(base, check_digit), Ok(true), "{full}");
+            for wrong in (0..10).filter(|&d| d != check_digit) {
+                assert_eq!(validate_split(base, wrong), Ok(false), "{base} {wrong}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_split_errors() {
+        assert_eq!(
+            validate_split("12345", 10),
+            Err(VerhoeffError::InvalidDigitValue(10))
+        );
+        assert_eq!(
+            validate_split("12345", u8::MAX),
+            Err(VerhoeffError::InvalidDigitValue(255))
+        );
+        assert_eq!(validate_split("", 0), Err(VerhoeffError::EmptyInput));
+        assert_eq!(
+            validate_split("12a45", 1),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 2 })
+        );
+    }
+
+    #[test]
+    fn test_single_digit_policy() {
+        // A single digit is a check digit over an empty base: only "0" is valid
+        assert!(validate("0"));
+        assert_eq!(validate_result("0"), Ok(true));
+        for d in '1'..='9' {
+            let input = d.to_string();
+            assert_eq!(validate_result(&input), Ok(false), "{input}");
+            assert_eq!(
+                validate_checked(&input),
+                Err(VerhoeffError::ChecksumMismatch {
+                    expected: 0,
+                    found: d as u8 - b'0'
+                })
+            );
+        }
+        assert_eq!(validate_checked("0"), Ok(()));
+
+        // Splitting is structural and yields the empty base
+        assert_eq!(split_checksum("0"), Ok(("", 0)));
+        assert_eq!(split_checksum("7"), Ok(("", 7)));
+        assert_eq!(breakdown("0").map(|b| (b.base, b.valid)), Ok(("", true)));
+
+        // Stripping promises a base that can be checksummed again
+        assert_eq!(validate_and_strip("0"), Err(VerhoeffError::EmptyInput));
+        assert_eq!(validate_and_strip("7"), Err(VerhoeffError::EmptyInput));
+        assert_eq!(validate_and_strip("04"), Ok("0"));
+        assert_eq!(
+            calculate_checksum_result(""),
+            Err(VerhoeffError::EmptyInput)
+        );
+
+        // Fixed-length helpers reject the length before looking at the digit
+        #[cfg(feature = "aadhaar")]
+        assert_eq!(
+            validate_aadhaar("0"),
+            Err(VerhoeffError::InvalidAadhaarLength(1))
+        );
+    }
+
+    #[test]
+    fn test_validate_and_strip() {
+        assert_eq!(validate_and_strip("123451"), Ok("12345"));
+        assert_eq!(validate_and_strip("123456789010"), Ok("12345678901"));
+        assert_eq!(validate_and_strip("0"), Err(VerhoeffError::EmptyInput));
+
+        assert_eq!(
+            validate_and_strip("2364"),
+            Err(VerhoeffError::ChecksumMismatch {
+                expected: 3,
+                found: 4
+            })
+        );
+        assert_eq!(
+            validate_and_strip("12a451"),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 2 })
+        );
+        assert_eq!(validate_and_strip(""), Err(VerhoeffError::EmptyInput));
+    }
+
+    #[test]
+    fn test_running_check() {
+        for input in ["0", "123451", "123452", "236", "2363", "123456789010"] {
+            assert_eq!(running_check(input).map(|c| c == 0), validate_result(input));
+        }
+        assert_eq!(running_check(""), Err(VerhoeffError::EmptyInput));
+        assert_eq!(
+            running_check("12a"),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 2 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_insert_then_validate_at() {
+        for base in ["1", "12345", "8473643095"] {
+            for index in 0..=base.len() {
+                let full = insert_checksum(base, index).unwrap();
+                assert_eq!(validate_at(&full, index), Ok(true), "{full} @ {index}");
+
+                // Every other digit at the check position is rejected
+                let check = full.as_bytes()[index];
+                for wrong in (b'0'..=b'9').filter(|&d| d != check) {
+                    let mut bytes = full.clone().into_bytes();
+                    bytes[index] = wrong;
+                    let tampered = String::from_utf8(bytes).unwrap();
+                    assert_eq!(validate_at(&tampered, index), Ok(false));
+                }
+            }
+
+            // The last position is the ordinary trailing check digit
+            assert_eq!(
+                insert_checksum(base, base.len()).unwrap(),
+                append_checksum(base)
+            );
+        }
+    }
+
+    #[test]
+    fn test_check_index_errors() {
+        assert_eq!(
+            validate_at("123451", 6),
+            Err(VerhoeffError::IndexOutOfBounds { index: 6, len: 6 })
+        );
+        assert_eq!(validate_at("", 0), Err(VerhoeffError::EmptyInput));
+        assert_eq!(
+            validate_at("12a451", 5),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 2 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_insert_checksum_errors() {
+        assert_eq!(
+            insert_checksum("12345", 6),
+            Err(VerhoeffError::IndexOutOfBounds { index: 6, len: 5 })
+        );
+        assert_eq!(insert_checksum("", 0), Err(VerhoeffError::EmptyInput));
+    }
+
+    #[test]
+    fn test_validate_fixed_length() {
+        assert_eq!(validate_fixed_length("123456789010", 12), Ok(true));
+        assert_eq!(validate_fixed_length("123456789012", 12), Ok(false));
+        assert_eq!(
+            validate_fixed_length("12345678901", 12),
+            Err(VerhoeffError::InvalidLength {
+                expected: 12,
+                got: 11
+            })
+        );
+        assert_eq!(
+            validate_fixed_length("1234567890100", 12),
+            Err(VerhoeffError::InvalidLength {
+                expected: 12,
+                got: 13
+            })
+        );
+        assert_eq!(
+            validate_fixed_length("12345678901a", 12),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 11 })
+        );
+        // Length is counted in characters, not bytes
+        assert_eq!(
+            validate_fixed_length("1२3", 3),
+            Err(VerhoeffError::InvalidCharacter {
+                ch: '२', index: 1
+            })
+        );
+        assert_eq!(validate_fixed_length("", 0), Err(VerhoeffError::EmptyInput));
+    }
+
+    #[test]
+    fn test_validate_checked() {
+        assert_eq!(validate_checked("123451"), Ok(()));
+        assert_eq!(validate_checked("2363"), Ok(()));
+        assert_eq!(
+            validate_checked("2364"),
+            Err(VerhoeffError::ChecksumMismatch {
+                expected: 3,
+                found: 4
+            })
+        );
+
+        // Agrees with the boolean API on well-formed input
+        for input in ["123451", "123452", "123456789010", "1"] {
+            assert_eq!(validate_checked(input).is_ok(), validate(input));
+        }
+
+        assert_eq!(validate_checked(""), Err(VerhoeffError::EmptyInput));
+        assert_eq!(
+            validate_checked("12 34"),
+            Err(VerhoeffError::InvalidCharacter { ch: ' ', index: 2 })
+        );
+    }
+
+    #[test]
+    fn test_const_checksum() {
+        const CHECK: u8 = calculate_checksum_const(&[1, 2, 3, 4, 5]);
+        const ID: [u8; 12] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 0];
+        const _: () = assert!(validate_const(&ID));
+
+        assert_eq!(CHECK, calculate_checksum("12345"));
+        assert_eq!(calculate_checksum_const(&[2, 3, 6]), 3);
+        assert_eq!(calculate_checksum_const(&[]), 0);
+        assert!(validate_const(&[2, 3, 6, 3]));
+        assert!(!validate_const(&[2, 3, 6, 4]));
+        assert!(!validate_const(&[]));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn test_const_checksum_rejects_non_digit_values() {
+        calculate_checksum_const(&[1, 2, b'3']);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        // Non-digit characters
+        assert!(calculate_checksum_result("12a45").is_err());
+        assert!(validate_result("12345a").is_err());
+
+        // Empty input
+        assert!(calculate_checksum_result("").is_err());
+        assert!(validate_result("").is_err());
+    }
+
+    #[test]
+    fn test_calculate_checksum_seeded() {
+        // Seed 0 is the standard algorithm
+        for input in ["236", "12345", "142857"] {
+            assert_eq!(
+                calculate_checksum_seeded(input, 0),
+                calculate_checksum_result(input)
+            );
+        }
+
+        // Chain two records: the first record's check digit seeds the second
+        let first = calculate_checksum("12345");
+        let chained = calculate_checksum_seeded("236", first).unwrap();
+        assert_eq!(first, 1);
+        assert_eq!(chained, 2);
+        assert_ne!(chained, calculate_checksum("236"));
+
+        // A different parent propagates into the child's check digit
+        let other_parent = calculate_checksum("142857");
+        assert_ne!(
+            calculate_checksum_seeded("236", other_parent).unwrap(),
+            chained
+        );
+
+        assert_eq!(
+            calculate_checksum_seeded("236", 10),
+            Err(VerhoeffError::InvalidDigitValue(10))
+        );
+        assert_eq!(
+            calculate_checksum_seeded("", 3),
+            Err(VerhoeffError::EmptyInput)
+        );
+    }
+
+    /// Reference char-by-char check used to pin the byte fast path
+    fn check_digits_by_char(s: &str) -> Result<()> {
+        if s.is_empty() {
+            return Err(VerhoeffError::EmptyInput);
+        }
+        match s.chars().enumerate().find(|(_, ch)| !ch.is_ascii_digit()) {
+            Some((index, ch)) => Err(VerhoeffError::InvalidCharacter { ch, index }),
+            None => Ok(()),
+        }
+    }
+
+    #[test]
+    fn test_byte_path_matches_char_path() {
+        let inputs = [
+            "",
+            "0",
+            "123451",
+            "12a45",
+            "a",
+            "12345 ",
+            "१२३",
+            "12३45",
+            "1€",
+            "12\u{0}3",
+            "999999999999x",
+            "١",
+            "12345678901२",
+        ];
+        for input in inputs {
+            assert_eq!(
+                check_digits(input),
+                check_digits_by_char(input),
+                "{input:?}"
+            );
+            assert_eq!(
+                validate_result(input).map(|_| ()),
+                check_digits_by_char(input),
+                "{input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_character_position() {
+        assert_eq!(
+            validate_result("123456789O12"),
+            Err(VerhoeffError::InvalidCharacter { ch: 'O', index: 9 })
+        );
+        assert_eq!(
+            calculate_checksum_result("a123"),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 0 })
+        );
+
+        // Position is counted in characters, not bytes
+        assert_eq!(
+            validate_result("1२3"),
+            Err(VerhoeffError::InvalidCharacter {
+                ch: '२', index: 1
+            })
+        );
+
+        let err = VerhoeffError::InvalidCharacter { ch: 'O', index: 11 };
+        assert_eq!(
+            err.to_string(),
+            "Invalid character 'O' at position 11 - only digits allowed"
+        );
+    }
+
+    #[test]
+    fn test_confusable_digits_rejected() {
+        // Fullwidth digits
+        assert_eq!(
+            validate_result("１２３"),
+            Err(VerhoeffError::InvalidCharacter {
+                ch: '１', index: 0
+            })
+        );
+        assert_eq!(
+            calculate_checksum_result("12３"),
+            Err(VerhoeffError::InvalidCharacter {
+                ch: '３', index: 2
+            })
+        );
+
+        // Superscripts
+        assert_eq!(
+            validate_result("¹²³"),
+            Err(VerhoeffError::InvalidCharacter { ch: '¹', index: 0 })
+        );
+        assert_eq!(
+            validate_result("12³"),
+            Err(VerhoeffError::InvalidCharacter { ch: '³', index: 2 })
+        );
+
+        // A combining mark is its own character, following the digit
+        assert_eq!(
+            validate_result("1\u{0301}23"),
+            Err(VerhoeffError::InvalidCharacter {
+                ch: '\u{0301}',
+                index: 1
+            })
+        );
+
+        // Opting in to Unicode digits accepts fullwidth, never superscripts
+        assert_eq!(validate_unicode("２３６３"), Ok(true));
+        assert!(validate_unicode("¹²³").is_err());
+    }
+
+    #[test]
+    fn test_invalid_character_display_shows_code_point() {
+        let display = |ch| VerhoeffError::InvalidCharacter { ch, index: 0 }.to_string();
+
+        assert_eq!(
+            display('１'),
+            "Invalid character '１' (U+FF11) at position 0 - only digits allowed"
+        );
+        assert_eq!(
+            display('¹'),
+            "Invalid character '¹' (U+00B9) at position 0 - only digits allowed"
+        );
+        assert_eq!(
+            display('\u{0301}'),
+            "Invalid character '\u{0301}' (U+0301) at position 0 - only digits allowed"
+        );
+        assert_eq!(
+            display('\t'),
+            "Invalid character '\t' (U+0009) at position 0 - only digits allowed"
+        );
+
+        // Printable ASCII is shown as is
+        assert_eq!(
+            display(' '),
+            "Invalid character ' ' at position 0 - only digits allowed"
+        );
+        assert_eq!(
+            display('-'),
+            "Invalid character '-' at position 0 - only digits allowed"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize_error() {
+        let cases = [
+            (
+                VerhoeffError::InvalidCharacter { ch: 'O', index: 11 },
+                r#"{"type":"InvalidCharacter","char":"O","index":11}"#,
+            ),
+            (
+                VerhoeffError::LikelyNonDecimal { ch: 'f', index: 0 },
+                r#"{"type":"LikelyNonDecimal","char":"f","index":0}"#,
+            ),
+            (VerhoeffError::EmptyInput, r#"{"type":"EmptyInput"}"#),
+            (
+                VerhoeffError::EmptyAfterNormalization,
+                r#"{"type":"EmptyAfterNormalization"}"#,
+            ),
+            (
+                VerhoeffError::InvalidAadhaarLength(11),
+                r#"{"type":"InvalidAadhaarLength","got":11}"#,
+            ),
+            (
+                VerhoeffError::InvalidVidLength(15),
+                r#"{"type":"InvalidVidLength","got":15}"#,
+            ),
+            (
+                VerhoeffError::InvalidAadhaarLeadingDigit(1),
+                r#"{"type":"InvalidAadhaarLeadingDigit","digit":1}"#,
+            ),
+            (
+                VerhoeffError::InvalidLength {
+                    expected: 12,
+                    got: 4,
+                },
+                r#"{"type":"InvalidLength","expected":12,"got":4}"#,
+            ),
+            (
+                VerhoeffError::LengthPrefixMismatch {
+                    declared: 6,
+                    got: 5,
+                },
+                r#"{"type":"LengthPrefixMismatch","declared":6,"got":5}"#,
+            ),
+            (
+                VerhoeffError::InvalidIdLength {
+                    name: "Device ID",
+                    expected: 10,
+                    got: 11,
+                },
+                r#"{"type":"InvalidIdLength","name":"Device ID","expected":10,"got":11}"#,
+            ),
+            (
+                VerhoeffError::InvalidDigitValue(10),
+                r#"{"type":"InvalidDigitValue","value":10}"#,
+            ),
+            (
+                VerhoeffError::InvalidPlaceholderCount(2),
+                r#"{"type":"InvalidPlaceholderCount","count":2}"#,
+            ),
+            (
+                VerhoeffError::IndexOutOfBounds { index: 5, len: 3 },
+                r#"{"type":"IndexOutOfBounds","index":5,"len":3}"#,
+            ),
+            (
+                VerhoeffError::InvalidSeparator('7'),
+                r#"{"type":"InvalidSeparator","char":"7"}"#,
+            ),
+            (
+                VerhoeffError::IntegerOverflow { bits: 64 },
+                r#"{"type":"IntegerOverflow","bits":64}"#,
+            ),
+            (VerhoeffError::Io, r#"{"type":"Io"}"#),
+            (
+                VerhoeffError::ChecksumMismatch {
+                    expected: 1,
+                    found: 2,
+                },
+                r#"{"type":"ChecksumMismatch","expected":1,"found":2}"#,
+            ),
+        ];
+
+        for (err, json) in cases {
+            assert_eq!(serde_json::to_string(&err).unwrap(), json);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_into_io_error() {
+        let errors = [
+            VerhoeffError::InvalidCharacter { ch: 'x', index: 3 },
+            VerhoeffError::LikelyNonDecimal { ch: 'f', index: 0 },
+            VerhoeffError::EmptyInput,
+            VerhoeffError::EmptyAfterNormalization,
+            VerhoeffError::InvalidAadhaarLength(11),
+            VerhoeffError::InvalidVidLength(15),
+            VerhoeffError::InvalidAadhaarLeadingDigit(1),
+            VerhoeffError::InvalidLength {
+                expected: 12,
+                got: 4,
+            },
+            VerhoeffError::LengthPrefixMismatch {
+                declared: 6,
+                got: 5,
+            },
+            VerhoeffError::InvalidIdLength {
+                name: "Device ID",
+                expected: 10,
+                got: 11,
+            },
+            VerhoeffError::InvalidDigitValue(10),
+            VerhoeffError::InvalidPlaceholderCount(2),
+            VerhoeffError::IndexOutOfBounds { index: 5, len: 3 },
+            VerhoeffError::InvalidSeparator('7'),
+            VerhoeffError::IntegerOverflow { bits: 64 },
+            VerhoeffError::Io,
+            VerhoeffError::ChecksumMismatch {
+                expected: 1,
+                found: 2,
+            },
+        ];
+
+        for err in errors {
+            let io_err = std::io::Error::from(err.clone());
+            assert_eq!(io_err.kind(), std::io::ErrorKind::InvalidData);
+            assert_eq!(io_err.to_string(), err.to_string());
+
+            let inner = io_err.into_inner().unwrap();
+            assert_eq!(inner.downcast_ref::<VerhoeffError>(), Some(&err));
+
+            let boxed: Box<dyn std::error::Error> = err.clone().into();
+            assert_eq!(boxed.to_string(), err.to_string());
+        }
+
+        fn read_check_digit(input: &str) -> std::io::Result<u8> {
+            Ok(calculate_checksum_result(input)?)
+        }
+        assert_eq!(read_check_digit("12345").unwrap(), 1);
+        assert_eq!(
+            read_check_digit("").unwrap_err().kind(),
+            std::io::ErrorKind::InvalidData
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_validate_detailed() {
+        assert_eq!(validate_detailed("2363"), DetailedResult::Valid);
+
+        // Hyphenated but otherwise valid input can be auto-fixed
+        assert_eq!(
+            validate_detailed("1234-5678-9010"),
+            DetailedResult::RecoverableInvalid {
+                reason: VerhoeffError::InvalidCharacter { ch: '-', index: 4 }
+            }
+        );
+        assert!(matches!(
+            validate_detailed(" 2363 "),
+            DetailedResult::RecoverableInvalid { .. }
+        ));
+
+        // A genuinely wrong check digit is fatal
+        assert_eq!(
+            validate_detailed("2364"),
+            DetailedResult::FatalInvalid {
+                reason: VerhoeffError::ChecksumMismatch {
+                    expected: 3,
+                    found: 4
+                }
+            }
+        );
+        // ...even when it is also formatted with separators
+        assert_eq!(
+            validate_detailed("23-64"),
+            DetailedResult::FatalInvalid {
+                reason: VerhoeffError::ChecksumMismatch {
+                    expected: 3,
+                    found: 4
+                }
+            }
+        );
+
+        // Stray letters cannot be fixed by canonicalization
+        assert!(matches!(
+            validate_detailed("23a63"),
+            DetailedResult::FatalInvalid {
+                reason: VerhoeffError::InvalidCharacter { ch: 'a', index: 2 }
+            }
+        ));
+
+        // Separators alone are not the same as empty input
+        for input in ["--", "   "] {
+            assert_eq!(
+                validate_detailed(input),
+                DetailedResult::FatalInvalid {
+                    reason: VerhoeffError::EmptyAfterNormalization
+                },
+                "{input:?}"
+            );
+        }
+        assert_eq!(
+            validate_detailed(""),
+            DetailedResult::FatalInvalid {
+                reason: VerhoeffError::EmptyInput
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_validate_detailed_hints_non_decimal() {
+        let result = validate_detailed("1a2b3c");
+        assert_eq!(
+            result,
+            DetailedResult::FatalInvalid {
+                reason: VerhoeffError::LikelyNonDecimal { ch: 'a', index: 1 }
+            }
+        );
+        let DetailedResult::FatalInvalid { reason } = result else {
+            unreachable!()
+        };
+        assert!(reason.to_string().contains("non-decimal (base-N?)"));
+        assert!(reason.to_string().contains("'a' at position 1"));
+
+        assert!(matches!(
+            validate_detailed("DEAD-BEEF"),
+            DetailedResult::FatalInvalid {
+                reason: VerhoeffError::LikelyNonDecimal { ch: 'D', index: 0 }
+            }
+        ));
+
+        // The strict validators keep the plain error
+        assert_eq!(
+            validate_result("1a2b3c"),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_looks_like_non_decimal() {
+        for input in ["1a2b3c", "deadbeef", "0x1f", "0X7", "ZX81", "12ab-cd34"] {
+            assert!(looks_like_non_decimal(input), "{input}");
+        }
+        for input in ["", "123451", "23a63", "0x", "12.ab", "१२ab", "1x2"] {
+            assert!(!looks_like_non_decimal(input), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_single_digit_error_detection() {
+        let base = "123456789";
+        let checksum = calculate_checksum(base);
+        let full = format!("{base}{checksum}");
+
+        // Change each digit and verify it's detected
+        for i in 0..full.len() {
+            let mut chars: Vec<char> = full.chars().collect();
+            let original = chars[i].to_digit(10).unwrap();
+
+            // Try changing to different digit
+            for new_digit in 0..10 {
                 if new_digit != original {
                     chars[i] = std::char::from_digit(new_digit, 10).unwrap();
                     let modified: String = chars.iter().collect();
@@ -386,4 +3311,3 @@ mod tests {
         }
     }
 }
-
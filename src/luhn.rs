@@ -0,0 +1,135 @@
+// FilePath: src/luhn.rs
+
+//! Luhn (mod 10) check digits, for interop with systems that use them
+//!
+//! Luhn is simpler than Verhoeff and catches every single-digit error, but
+//! it does **not** catch every adjacent transposition: swapping `09` and
+//! `90` leaves the Luhn sum unchanged. Prefer Verhoeff when you control the
+//! scheme; use this module when you must interoperate with Luhn numbers such
+//! as payment card numbers.
+//!
+//! Input validation follows the rest of the crate and reports problems as
+//! [`VerhoeffError`](crate::VerhoeffError).
+//!
+//! ```
+//! use verhoeff::luhn;
+//!
+//! assert_eq!(luhn::calculate_check_digit("7992739871"), Ok(3));
+//! assert!(luhn::validate("79927398713"));
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+use crate::{check_digits, digits_rev, Result};
+
+/// Sums digits given rightmost first, doubling every digit at an odd
+/// position (the second, fourth, ... from the right)
+fn luhn_sum(digits: impl Iterator<Item = u8>) -> u32 {
+    digits
+        .enumerate()
+        .map(|(i, digit)| {
+            let digit = u32::from(digit);
+            if i % 2 == 1 {
+                let doubled = digit * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                digit
+            }
+        })
+        .sum()
+}
+
+/// Calculate the Luhn check digit for a string of digits.
+///
+/// # Returns
+///
+/// * `Ok(u8)` - The check digit (0-9) to append
+/// * `Err(VerhoeffError)` - If the input is empty or contains non-digits
+pub fn calculate_check_digit(input: &str) -> Result<u8> {
+    check_digits(input)?;
+
+    // The check digit will occupy position 0, so the base starts doubled
+    let sum = luhn_sum(core::iter::once(0).chain(digits_rev(input)));
+    Ok(((10 - sum % 10) % 10) as u8)
+}
+
+/// Validate a number whose last digit is a Luhn check digit.
+///
+/// Returns `false` for malformed input.
+pub fn validate(input: &str) -> bool {
+    check_digits(input).is_ok() && luhn_sum(digits_rev(input)) % 10 == 0
+}
+
+/// Append a Luhn check digit to a number.
+///
+/// Like [`append_checksum`](crate::append_checksum), malformed input is
+/// returned unchanged.
+#[cfg(feature = "alloc")]
+pub fn append_check_digit(input: &str) -> String {
+    match calculate_check_digit(input) {
+        Ok(check) => format!("{input}{check}"),
+        Err(_) => input.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VerhoeffError;
+
+    #[test]
+    fn test_known_values() {
+        assert_eq!(calculate_check_digit("7992739871"), Ok(3));
+        assert!(validate("79927398713"));
+        assert!(!validate("79927398710"));
+
+        // A well-known test card number
+        assert!(validate("4111111111111111"));
+        assert_eq!(calculate_check_digit("411111111111111"), Ok(1));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_append_round_trip() {
+        for base in ["0", "1", "12345", "7992739871", "987654321"] {
+            let full = append_check_digit(base);
+            assert!(validate(&full), "'{full}' should validate");
+        }
+        assert_eq!(append_check_digit("12a"), "12a");
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert_eq!(calculate_check_digit(""), Err(VerhoeffError::EmptyInput));
+        assert_eq!(
+            calculate_check_digit("12a"),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 2 })
+        );
+        assert!(!validate(""));
+        assert!(!validate("7992739871x"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_transposition_luhn_misses() {
+        // Luhn cannot tell 09 from 90; Verhoeff can
+        let luhn_number = append_check_digit("1090");
+        let swapped = luhn_number.replacen("09", "90", 1);
+        assert_ne!(luhn_number, swapped);
+        assert!(validate(&luhn_number));
+        assert!(validate(&swapped), "Luhn unexpectedly caught 09 <-> 90");
+
+        let verhoeff_number = crate::append_checksum("1090");
+        let swapped = verhoeff_number.replacen("09", "90", 1);
+        assert!(crate::validate(&verhoeff_number));
+        assert!(!crate::validate(&swapped), "Verhoeff missed 09 <-> 90");
+    }
+}
@@ -0,0 +1,106 @@
+// FilePath: src/masked.rs
+
+//! Log-safe formatting of identifiers with most characters hidden
+
+use core::fmt::{self, Write};
+
+/// A number formatted with all but its last few characters replaced by `*`.
+///
+/// This generalizes `mask_aadhaar` to any string. It does not validate and
+/// does not allocate, so it is cheap to use in log statements. Both
+/// `Display` and `Debug` print the masked form, so the full value cannot
+/// leak through either.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::Masked;
+///
+/// let masked = Masked::new("123456789010", 4);
+/// assert_eq!(masked.to_string(), "********9010");
+/// assert_eq!(format!("{masked:?}"), "Masked(\"********9010\")");
+/// ```
+#[derive(Clone, Copy)]
+pub struct Masked<'a> {
+    number: &'a str,
+    visible_tail: usize,
+}
+
+impl<'a> Masked<'a> {
+    /// Wrap `number`, leaving its last `visible_tail` characters visible.
+    ///
+    /// A `visible_tail` of 0 hides everything; one at least the length of
+    /// the number shows it unchanged.
+    pub const fn new(number: &'a str, visible_tail: usize) -> Self {
+        Masked {
+            number,
+            visible_tail,
+        }
+    }
+}
+
+impl fmt::Display for Masked<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let hidden = self
+            .number
+            .chars()
+            .count()
+            .saturating_sub(self.visible_tail);
+        let (split, _) = self
+            .number
+            .char_indices()
+            .nth(hidden)
+            .unwrap_or((self.number.len(), ' '));
+
+        for _ in 0..hidden {
+            f.write_char('*')?;
+        }
+        f.write_str(&self.number[split..])
+    }
+}
+
+impl fmt::Debug for Masked<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Masked(\"{self}\")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_visible_tail() {
+        assert_eq!(
+            format!("{}", Masked::new("123456789010", 4)),
+            "********9010"
+        );
+        assert_eq!(
+            format!("{}", Masked::new("123456789010", 0)),
+            "************"
+        );
+        assert_eq!(
+            format!("{}", Masked::new("123456789010", 12)),
+            "123456789010"
+        );
+        assert_eq!(format!("{}", Masked::new("1234", 10)), "1234");
+        assert_eq!(format!("{}", Masked::new("", 4)), "");
+    }
+
+    #[test]
+    fn test_counts_characters_not_bytes() {
+        assert_eq!(format!("{}", Masked::new("१२३४५१", 2)), "****५१");
+    }
+
+    #[test]
+    fn test_debug_never_shows_hidden_digits() {
+        let debug = format!("{:?}", Masked::new("123456789010", 4));
+        assert_eq!(debug, "Masked(\"********9010\")");
+        assert!(!debug.contains("12345678"));
+    }
+
+    #[test]
+    fn test_does_not_validate() {
+        assert_eq!(format!("{}", Masked::new("ab-cd", 2)), "***cd");
+    }
+}
@@ -0,0 +1,212 @@
+// FilePath: src/membership.rs
+
+//! Checksum validation combined with set membership
+
+use std::collections::HashSet;
+use std::hash::BuildHasher;
+
+use crate::{validate, validate_result, Result};
+
+/// Outcome of [`validate_not_blocklisted`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlocklistResult {
+    /// The check digit is wrong; the blocklist was not consulted
+    BadChecksum,
+    /// The number is checksum-valid but appears in the blocklist
+    Blocklisted,
+    /// The number is checksum-valid and not blocklisted
+    Clean,
+}
+
+/// Validate a number and confirm it is not in a blocklist.
+///
+/// The checksum is checked first, so malformed or invalid input is rejected
+/// without hashing it. The blocklist is matched against the input exactly
+/// as given.
+///
+/// # Returns
+///
+/// * `Ok(BlocklistResult)` - The outcome for well-formed input
+/// * `Err(VerhoeffError)` - If the input is malformed
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashSet;
+/// use verhoeff::{validate_not_blocklisted, BlocklistResult};
+///
+/// let revoked: HashSet<String> = ["123451".to_string()].into();
+/// assert_eq!(validate_not_blocklisted("123451", &revoked), Ok(BlocklistResult::Blocklisted));
+/// assert_eq!(validate_not_blocklisted("2363", &revoked), Ok(BlocklistResult::Clean));
+/// ```
+pub fn validate_not_blocklisted<S: BuildHasher>(
+    input: &str,
+    blocklist: &HashSet<String, S>,
+) -> Result<BlocklistResult> {
+    if !validate_result(input)? {
+        return Ok(BlocklistResult::BadChecksum);
+    }
+
+    if blocklist.contains(input) {
+        Ok(BlocklistResult::Blocklisted)
+    } else {
+        Ok(BlocklistResult::Clean)
+    }
+}
+
+/// A set of numbers that are all known to carry a valid check digit.
+///
+/// Built with [`FromIterator`], which validates every item and drops the
+/// invalid or malformed ones; [`dropped`](Self::dropped) reports how many.
+/// Queries also validate their input, so a lookup both checks the checksum
+/// and membership.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::VerhoeffSet;
+///
+/// let allowed: VerhoeffSet = ["123451", "2363", "2364"].into_iter().collect();
+/// assert_eq!(allowed.len(), 2);
+/// assert_eq!(allowed.dropped(), 1);
+///
+/// assert!(allowed.contains_valid("2363"));
+/// assert!(!allowed.contains_valid("2364"));
+/// assert!(!allowed.contains_valid("8473643095"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct VerhoeffSet {
+    valid: HashSet<String>,
+    dropped: usize,
+}
+
+impl VerhoeffSet {
+    /// Whether `input` is checksum-valid and in the set.
+    pub fn contains_valid(&self, input: &str) -> bool {
+        validate(input) && self.valid.contains(input)
+    }
+
+    /// The number of items rejected while building the set, because they
+    /// were malformed or their check digit was wrong.
+    ///
+    /// Duplicates of an accepted item are not counted.
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    /// The number of distinct valid items in the set.
+    pub fn len(&self) -> usize {
+        self.valid.len()
+    }
+
+    /// Whether the set holds no valid items.
+    pub fn is_empty(&self) -> bool {
+        self.valid.is_empty()
+    }
+}
+
+/// Validates each item, keeping the valid ones and counting the rest.
+impl<S: Into<String>> FromIterator<S> for VerhoeffSet {
+    fn from_iter<I: IntoIterator<Item = S>>(items: I) -> Self {
+        let mut set = VerhoeffSet::default();
+        for item in items {
+            let item = item.into();
+            if validate(&item) {
+                set.valid.insert(item);
+            } else {
+                set.dropped += 1;
+            }
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VerhoeffError;
+
+    fn blocklist() -> HashSet<String> {
+        ["123456789010", "2363", "2364"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_blocklisted_but_valid() {
+        assert_eq!(
+            validate_not_blocklisted("123456789010", &blocklist()),
+            Ok(BlocklistResult::Blocklisted)
+        );
+    }
+
+    #[test]
+    fn test_clean_valid() {
+        assert_eq!(
+            validate_not_blocklisted("123451", &blocklist()),
+            Ok(BlocklistResult::Clean)
+        );
+    }
+
+    #[test]
+    fn test_bad_checksum_is_not_blocklisted() {
+        // "2364" is in the blocklist, but its checksum fails first
+        assert_eq!(
+            validate_not_blocklisted("2364", &blocklist()),
+            Ok(BlocklistResult::BadChecksum)
+        );
+    }
+
+    #[test]
+    fn test_malformed() {
+        assert_eq!(
+            validate_not_blocklisted("23a3", &blocklist()),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 2 })
+        );
+        assert_eq!(
+            validate_not_blocklisted("", &blocklist()),
+            Err(VerhoeffError::EmptyInput)
+        );
+    }
+
+    #[test]
+    fn test_verhoeff_set() {
+        let items = [
+            "123456789010", // valid
+            "123456789013", // bad checksum
+            "2363",         // valid
+            "2363",         // duplicate
+            "12a45",        // malformed
+            "",             // empty
+            "0",            // valid
+        ];
+        let set: VerhoeffSet = items.into_iter().collect();
+
+        assert_eq!(set.len(), 3);
+        assert_eq!(set.dropped(), 3);
+        assert!(!set.is_empty());
+
+        assert!(set.contains_valid("123456789010"));
+        assert!(set.contains_valid("2363"));
+        assert!(set.contains_valid("0"));
+
+        // Rejected items and valid non-members are both absent
+        assert!(!set.contains_valid("123456789013"));
+        assert!(!set.contains_valid("12a45"));
+        assert!(!set.contains_valid(""));
+        assert!(!set.contains_valid("123451"));
+    }
+
+    #[test]
+    fn test_verhoeff_set_from_strings() {
+        let set = VerhoeffSet::from_iter(vec![String::from("123451"), String::from("123452")]);
+        assert_eq!(set.len(), 1);
+        assert_eq!(set.dropped(), 1);
+        assert!(set.contains_valid("123451"));
+
+        let empty: VerhoeffSet = core::iter::empty::<&str>().collect();
+        assert!(empty.is_empty());
+        assert_eq!(empty.dropped(), 0);
+    }
+}
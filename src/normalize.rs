@@ -0,0 +1,314 @@
+// FilePath: src/normalize.rs
+
+//! Validation of user-formatted input
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+
+use crate::{accumulate, Result, VerhoeffError, INV_TABLE};
+
+/// Checks that `input` contains only digits and `separators`, with at least
+/// one digit, reporting the first offending character
+fn check_separated(input: &str, separators: &[char]) -> Result<()> {
+    let mut has_digit = false;
+
+    for (index, ch) in input.chars().enumerate() {
+        if separators.contains(&ch) {
+            continue;
+        }
+        if !ch.is_ascii_digit() {
+            return Err(VerhoeffError::InvalidCharacter { ch, index });
+        }
+        has_digit = true;
+    }
+
+    if has_digit {
+        Ok(())
+    } else {
+        Err(no_digits(input))
+    }
+}
+
+/// The error for input without digits: `EmptyInput` if it was literally
+/// empty, `EmptyAfterNormalization` if only formatting was stripped
+pub(crate) fn no_digits(input: &str) -> VerhoeffError {
+    if input.is_empty() {
+        VerhoeffError::EmptyInput
+    } else {
+        VerhoeffError::EmptyAfterNormalization
+    }
+}
+
+/// Iterates the digit values of checked input in reverse, skipping separators
+fn separated_digits_rev<'a>(
+    input: &'a str,
+    separators: &'a [char],
+) -> impl Iterator<Item = u8> + 'a {
+    input
+        .chars()
+        .rev()
+        .filter(move |c| !separators.contains(c))
+        .map(|c| c as u8 - b'0')
+}
+
+/// Validate a number after stripping the listed separator characters.
+///
+/// Separators may appear anywhere, including leading, trailing and in runs.
+/// Any other non-digit character is still rejected with
+/// `VerhoeffError::InvalidCharacter`, whose index refers to the original
+/// input. Empty input yields `VerhoeffError::EmptyInput`, and input made up
+/// only of separators yields `VerhoeffError::EmptyAfterNormalization`.
+/// Nothing is allocated.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::validate_with_separators;
+///
+/// assert_eq!(validate_with_separators("1234 5678 9010", &[' ']), Ok(true));
+/// assert_eq!(validate_with_separators("1234-5678-9010", &[' ', '-']), Ok(true));
+/// assert!(validate_with_separators("1234_5678_9010", &[' ']).is_err());
+/// ```
+pub fn validate_with_separators(input: &str, separators: &[char]) -> Result<bool> {
+    check_separated(input, separators)?;
+    Ok(accumulate(separated_digits_rev(input, separators), 0, 0) == 0)
+}
+
+/// Calculate the checksum digit of a number after stripping the listed
+/// separator characters.
+///
+/// Follows the same rules as [`validate_with_separators`].
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::calculate_checksum_with_separators;
+///
+/// assert_eq!(calculate_checksum_with_separators("1234 5678 901", &[' ']), Ok(0));
+/// ```
+pub fn calculate_checksum_with_separators(input: &str, separators: &[char]) -> Result<u8> {
+    check_separated(input, separators)?;
+    let c = accumulate(separated_digits_rev(input, separators), 1, 0);
+    Ok(INV_TABLE[c as usize])
+}
+
+/// Remove the listed separator characters, allocating only if one is present.
+///
+/// Returns `Cow::Borrowed(input)` when `input` contains none of
+/// `separators`, so already-clean input costs a single scan. Nothing else is
+/// checked: the result can be passed straight to the strict functions such
+/// as [`validate_result`](crate::validate_result), which report any
+/// remaining non-digit.
+///
+/// # Example
+///
+/// ```
+/// use std::borrow::Cow;
+/// use verhoeff::{normalize_digits, validate};
+///
+/// assert!(matches!(normalize_digits("123451", &[' ']), Cow::Borrowed("123451")));
+///
+/// let normalized = normalize_digits("1234 5678 9010", &[' ', '-']);
+/// assert_eq!(normalized, "123456789010");
+/// assert!(validate(&normalized));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn normalize_digits<'a>(input: &'a str, separators: &[char]) -> Cow<'a, str> {
+    match input.find(separators) {
+        None => Cow::Borrowed(input),
+        Some(first) => {
+            let mut stripped = String::with_capacity(input.len() - 1);
+            stripped.push_str(&input[..first]);
+            stripped.extend(input[first..].chars().filter(|c| !separators.contains(c)));
+            Cow::Owned(stripped)
+        }
+    }
+}
+
+/// Iterates the ASCII digit values of `input` in order, skipping everything
+/// else
+fn ascii_digits(input: &str) -> impl DoubleEndedIterator<Item = u8> + '_ {
+    input.bytes().filter(u8::is_ascii_digit).map(|b| b - b'0')
+}
+
+/// Compare two inputs by their digit sequences only.
+///
+/// Every character other than an ASCII digit is ignored, so formatting
+/// differences such as spaces or dashes never cause a mismatch. Two inputs
+/// without any digits compare equal.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::digits_equal;
+///
+/// assert!(digits_equal("1234 5678", "12345678"));
+/// assert!(digits_equal("1234-5678", " 1234 5678 "));
+/// assert!(!digits_equal("1234 5678", "1234 5679"));
+/// ```
+pub fn digits_equal(a: &str, b: &str) -> bool {
+    ascii_digits(a).eq(ascii_digits(b))
+}
+
+/// Validate a number after discarding every non-digit character.
+///
+/// This is the lenient counterpart of the strict
+/// [`validate_result`](crate::validate_result): instead of rejecting
+/// unexpected characters, it validates whatever ASCII digits remain, so
+/// `"1234-5678-9010"`, `"1234 5678 9010"` and `"ID 1234/5678/9010"` all
+/// give the same answer. Use it only where stray characters are known to be
+/// formatting; [`validate_with_separators`] accepts a fixed set of
+/// separators and still rejects anything else. Empty input yields
+/// `VerhoeffError::EmptyInput`, and input without any digits yields
+/// `VerhoeffError::EmptyAfterNormalization`.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{validate, validate_normalized};
+///
+/// assert_eq!(validate_normalized("1234-5678-9010"), Ok(true));
+/// assert!(!validate("1234-5678-9010"));
+/// ```
+pub fn validate_normalized(input: &str) -> Result<bool> {
+    let mut digits = ascii_digits(input).rev().peekable();
+    if digits.peek().is_none() {
+        return Err(no_digits(input));
+    }
+    Ok(accumulate(digits, 0, 0) == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{calculate_checksum, validate, validate_result};
+
+    const SEPARATORS: &[char] = &[' ', '-'];
+
+    #[test]
+    fn test_separators_are_stripped() {
+        assert_eq!(
+            validate_with_separators("1234 5678 9010", SEPARATORS),
+            Ok(true)
+        );
+        assert_eq!(
+            validate_with_separators("1234-5678-9013", SEPARATORS),
+            Ok(false)
+        );
+        assert_eq!(
+            calculate_checksum_with_separators("1-2-3-4-5", SEPARATORS),
+            Ok(calculate_checksum("12345"))
+        );
+    }
+
+    #[test]
+    fn test_separator_edge_cases() {
+        // Leading, trailing and runs of separators
+        assert_eq!(validate_with_separators("  2363", SEPARATORS), Ok(true));
+        assert_eq!(validate_with_separators("2363--", SEPARATORS), Ok(true));
+        assert_eq!(
+            validate_with_separators("2 - - 3 6 3", SEPARATORS),
+            Ok(true)
+        );
+
+        // Nothing left after stripping
+        assert_eq!(
+            validate_with_separators(" - ", SEPARATORS),
+            Err(VerhoeffError::EmptyAfterNormalization)
+        );
+        assert_eq!(
+            validate_with_separators("   ", &[' ']),
+            Err(VerhoeffError::EmptyAfterNormalization)
+        );
+        assert_eq!(
+            calculate_checksum_with_separators("----", SEPARATORS),
+            Err(VerhoeffError::EmptyAfterNormalization)
+        );
+
+        // A literally empty input is still `EmptyInput`
+        assert_eq!(
+            validate_with_separators("", SEPARATORS),
+            Err(VerhoeffError::EmptyInput)
+        );
+        assert_eq!(
+            calculate_checksum_with_separators("", SEPARATORS),
+            Err(VerhoeffError::EmptyInput)
+        );
+    }
+
+    #[test]
+    fn test_other_characters_still_rejected() {
+        assert_eq!(
+            validate_with_separators("1234 5678.9010", SEPARATORS),
+            Err(VerhoeffError::InvalidCharacter { ch: '.', index: 9 })
+        );
+        // Without separators configured this is exactly `validate_result`
+        assert_eq!(
+            validate_with_separators("2363 ", &[]),
+            Err(VerhoeffError::InvalidCharacter { ch: ' ', index: 4 })
+        );
+        assert_eq!(validate_with_separators("2363", &[]), Ok(validate("2363")));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_normalize_digits_borrows_clean_input() {
+        for input in ["123451", "", "12a45", "1234_5678"] {
+            let normalized = normalize_digits(input, SEPARATORS);
+            assert!(
+                matches!(normalized, Cow::Borrowed(s) if s == input),
+                "{input}"
+            );
+        }
+
+        // No separators configured never allocates
+        assert!(matches!(normalize_digits("12 34", &[]), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_normalize_digits_strips_separators() {
+        for (input, expected) in [
+            ("1234 5678 9010", "123456789010"),
+            ("-2-3-6-3-", "2363"),
+            ("  ", ""),
+            ("12 a-45", "12a45"),
+        ] {
+            let normalized = normalize_digits(input, SEPARATORS);
+            assert!(matches!(normalized, Cow::Owned(_)), "{input}");
+            assert_eq!(normalized, expected);
+        }
+
+        let normalized = normalize_digits("1234-5678-9010", SEPARATORS);
+        assert_eq!(validate_result(&normalized), Ok(true));
+    }
+
+    #[test]
+    fn test_digits_equal() {
+        assert!(digits_equal("1234 5678", "12345678"));
+        assert!(digits_equal("12-34\t5678", "1234 5678"));
+        assert!(digits_equal("", " - "));
+
+        assert!(!digits_equal("1234 5678", "1234 567"));
+        assert!(!digits_equal("1234 5678", "2134 5678"));
+    }
+
+    #[test]
+    fn test_validate_normalized() {
+        // 123456789 has check digit 0
+        assert_eq!(calculate_checksum("123456789"), 0);
+        assert_eq!(validate_normalized("1234-5678-90"), Ok(true));
+        assert_eq!(
+            validate_normalized("1234-5678-91"),
+            validate_result("1234567891")
+        );
+        assert_eq!(validate_normalized("ID: 1234 5678 9010."), Ok(true));
+        assert_eq!(validate_normalized("1234-5678-9013"), Ok(false));
+
+        assert_eq!(validate_normalized(""), Err(VerhoeffError::EmptyInput));
+        assert_eq!(
+            validate_normalized("n/a"),
+            Err(VerhoeffError::EmptyAfterNormalization)
+        );
+    }
+}
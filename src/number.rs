@@ -0,0 +1,260 @@
+// FilePath: src/number.rs
+
+//! A string newtype that is proven to carry a valid Verhoeff check digit
+
+use alloc::string::{String, ToString};
+use core::borrow::Borrow;
+use core::fmt;
+use core::ops::Deref;
+use core::str::FromStr;
+
+use crate::{validate_checked, VerhoeffError};
+
+/// A number whose trailing Verhoeff check digit is known to be valid.
+///
+/// The only way to obtain a `VerhoeffNumber` is by parsing, which validates
+/// the checksum, so holding one is proof that the number is well-formed.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{VerhoeffError, VerhoeffNumber};
+///
+/// let n: VerhoeffNumber = "123451".parse()?;
+/// assert_eq!(n.base(), "12345");
+/// assert_eq!(n.check_digit(), 1);
+/// assert_eq!(n.to_string(), "123451");
+///
+/// assert!("123452".parse::<VerhoeffNumber>().is_err());
+///
+/// // It compares equal to, and derefs to, the full number
+/// assert_eq!(n, "123451");
+/// assert!(n.starts_with("123"));
+/// # Ok::<(), VerhoeffError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VerhoeffNumber(String);
+
+impl VerhoeffNumber {
+    /// The number without its check digit.
+    ///
+    /// For a single-digit number this is the empty string.
+    pub fn base(&self) -> &str {
+        &self.0[..self.0.len() - 1]
+    }
+
+    /// The trailing check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[self.0.len() - 1] - b'0'
+    }
+}
+
+impl FromStr for VerhoeffNumber {
+    type Err = VerhoeffError;
+
+    /// Parse a full number including its trailing check digit.
+    ///
+    /// Returns the usual character/empty errors for malformed input and
+    /// `VerhoeffError::ChecksumMismatch` when the check digit is wrong.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        validate_checked(s)?;
+        Ok(VerhoeffNumber(s.to_string()))
+    }
+}
+
+/// Validates the checksum exactly like [`FromStr`].
+impl TryFrom<&str> for VerhoeffNumber {
+    type Error = VerhoeffError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Validates the checksum exactly like [`FromStr`], keeping the string's
+/// allocation when it is valid.
+impl TryFrom<String> for VerhoeffNumber {
+    type Error = VerhoeffError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        validate_checked(&s)?;
+        Ok(VerhoeffNumber(s))
+    }
+}
+
+impl fmt::Display for VerhoeffNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Deref for VerhoeffNumber {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for VerhoeffNumber {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Lets a `HashMap` or `BTreeMap` keyed by `VerhoeffNumber` be queried with
+/// a plain `&str`.
+impl Borrow<str> for VerhoeffNumber {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for VerhoeffNumber {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for VerhoeffNumber {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<VerhoeffNumber> for str {
+    fn eq(&self, other: &VerhoeffNumber) -> bool {
+        self == other.0
+    }
+}
+
+impl PartialEq<VerhoeffNumber> for &str {
+    fn eq(&self, other: &VerhoeffNumber) -> bool {
+        *self == other.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for VerhoeffNumber {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+/// Deserializes from a string, rejecting malformed numbers and numbers whose
+/// check digit does not validate.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for VerhoeffNumber {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        VerhoeffNumber::try_from(s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_valid() {
+        let n: VerhoeffNumber = "123456789010".parse().unwrap();
+        assert_eq!(n.base(), "12345678901");
+        assert_eq!(n.check_digit(), 0);
+        assert_eq!(n.to_string(), "123456789010");
+
+        let single: VerhoeffNumber = "0".parse().unwrap();
+        assert_eq!(single.base(), "");
+        assert_eq!(single.check_digit(), 0);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "2364".parse::<VerhoeffNumber>(),
+            Err(VerhoeffError::ChecksumMismatch {
+                expected: 3,
+                found: 4
+            })
+        );
+        assert_eq!("".parse::<VerhoeffNumber>(), Err(VerhoeffError::EmptyInput));
+        assert_eq!(
+            "23a3".parse::<VerhoeffNumber>(),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 2 })
+        );
+    }
+
+    #[test]
+    fn test_try_from() {
+        let n = VerhoeffNumber::try_from("123451").unwrap();
+        assert_eq!(n.base(), "12345");
+
+        // A valid String is moved in without reallocating
+        let owned = String::from("123456789010");
+        let ptr = owned.as_ptr();
+        let n = VerhoeffNumber::try_from(owned).unwrap();
+        assert_eq!(n.0.as_ptr(), ptr);
+
+        assert_eq!(
+            VerhoeffNumber::try_from("123452"),
+            Err(VerhoeffError::ChecksumMismatch {
+                expected: 1,
+                found: 2
+            })
+        );
+        assert_eq!(
+            VerhoeffNumber::try_from(String::new()),
+            Err(VerhoeffError::EmptyInput)
+        );
+    }
+
+    #[test]
+    fn test_compares_with_str() {
+        let n: VerhoeffNumber = "123451".parse().unwrap();
+        assert_eq!(n, "123451");
+        assert_eq!("123451", n);
+        assert_eq!(n, *"123451");
+        assert_ne!(n, "12345");
+
+        assert_eq!(n.as_ref(), "123451");
+        assert_eq!(n.len(), 6);
+        assert!(n.ends_with('1'));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_map_key() {
+        use std::collections::HashMap;
+
+        let mut owners = HashMap::new();
+        owners.insert("123451".parse::<VerhoeffNumber>().unwrap(), "alice");
+        owners.insert("2363".parse::<VerhoeffNumber>().unwrap(), "bob");
+
+        let key: VerhoeffNumber = "2363".parse().unwrap();
+        assert_eq!(owners.get(&key), Some(&"bob"));
+        assert_eq!(owners.get("123451"), Some(&"alice"));
+        assert_eq!(owners.get("123452"), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let n: VerhoeffNumber = "123456789010".parse().unwrap();
+        let json = serde_json::to_string(&n).unwrap();
+        assert_eq!(json, "\"123456789010\"");
+
+        let back: VerhoeffNumber = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, n);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_rejects_tampered_checksum() {
+        let err = serde_json::from_str::<VerhoeffNumber>("\"123456789013\"").unwrap_err();
+        assert!(err.to_string().contains("Checksum mismatch"), "{err}");
+
+        let err = serde_json::from_str::<VerhoeffNumber>("\"12345678901a\"").unwrap_err();
+        assert!(err.to_string().contains("Invalid character"), "{err}");
+
+        assert!(serde_json::from_str::<VerhoeffNumber>("123456789010").is_err());
+    }
+}
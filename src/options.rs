@@ -0,0 +1,282 @@
+// FilePath: src/options.rs
+
+//! Configurable normalization of input before validation
+
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+
+use crate::normalize::no_digits;
+use crate::unicode::decimal_value;
+use crate::{accumulate, Result, VerhoeffError, INV_TABLE};
+
+/// Options controlling how input is normalized before the algorithm runs.
+///
+/// The default options are strict: only ASCII digits are accepted, exactly
+/// like [`validate_result`](crate::validate_result). Each builder method
+/// relaxes one rule, and the rules combine freely. Error indices always
+/// refer to characters of the original input.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::ValidateOptions;
+///
+/// let options = ValidateOptions::new()
+///     .allow_separators(&[' ', '-'])
+///     .allow_unicode_digits(true)
+///     .trim_whitespace(true);
+///
+/// assert_eq!(options.validate("  1234-5678-९०१० "), Ok(true));
+/// assert!(ValidateOptions::new().validate("1234-5678-9010").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ValidateOptions<'a> {
+    separators: &'a [char],
+    unicode_digits: bool,
+    trim_whitespace: bool,
+}
+
+impl<'a> ValidateOptions<'a> {
+    /// Create the default, strict ASCII-only options.
+    pub const fn new() -> Self {
+        ValidateOptions {
+            separators: &[],
+            unicode_digits: false,
+            trim_whitespace: false,
+        }
+    }
+
+    /// Skip the listed separator characters wherever they appear, as
+    /// [`validate_with_separators`](crate::validate_with_separators) does.
+    pub const fn allow_separators(mut self, separators: &'a [char]) -> Self {
+        self.separators = separators;
+        self
+    }
+
+    /// Accept decimal digits from any script, as
+    /// [`validate_unicode`](crate::validate_unicode) does.
+    pub const fn allow_unicode_digits(mut self, allow: bool) -> Self {
+        self.unicode_digits = allow;
+        self
+    }
+
+    /// Ignore leading and trailing whitespace.
+    pub const fn trim_whitespace(mut self, trim: bool) -> Self {
+        self.trim_whitespace = trim;
+        self
+    }
+
+    /// Validate a number ending in its check digit under these options.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` - If the checksum is valid
+    /// * `Ok(false)` - If the checksum is invalid
+    /// * `Err(VerhoeffError::EmptyInput)` - If the input is empty
+    /// * `Err(VerhoeffError::EmptyAfterNormalization)` - If the input only
+    ///   holds separators or whitespace
+    /// * `Err(VerhoeffError)` - If a character is not accepted
+    pub fn validate(&self, input: &str) -> Result<bool> {
+        let digits = self.check(input)?;
+        Ok(accumulate(self.digits_rev(digits), 0, 0) == 0)
+    }
+
+    /// Calculate the check digit of a number under these options.
+    ///
+    /// Follows the same rules as [`validate`](Self::validate).
+    pub fn calculate_checksum(&self, input: &str) -> Result<u8> {
+        let digits = self.check(input)?;
+        let c = accumulate(self.digits_rev(digits), 1, 0);
+        Ok(INV_TABLE[c as usize])
+    }
+
+    /// Append the check digit to a number under these options.
+    ///
+    /// The input is kept as written, minus any trimmed whitespace, with the
+    /// ASCII check digit added at the end.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use verhoeff::ValidateOptions;
+    ///
+    /// let options = ValidateOptions::new().allow_separators(&[' ']);
+    /// assert_eq!(options.append_checksum("1234 5"), Ok("1234 51".to_string()));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn append_checksum(&self, input: &str) -> Result<String> {
+        let digits = self.check(input)?;
+        let c = accumulate(self.digits_rev(digits), 1, 0);
+        Ok(format!("{digits}{}", INV_TABLE[c as usize]))
+    }
+
+    /// Value of `ch` if it counts as a digit under these options
+    fn digit(&self, ch: char) -> Option<u8> {
+        if ch.is_ascii_digit() {
+            Some(ch as u8 - b'0')
+        } else if self.unicode_digits {
+            decimal_value(ch)
+        } else {
+            None
+        }
+    }
+
+    /// Checks `input` and returns the slice left after trimming, reporting
+    /// the first rejected character by its index in the original input
+    fn check<'s>(&self, input: &'s str) -> Result<&'s str> {
+        let (trimmed, offset) = if self.trim_whitespace {
+            let start = input.trim_start();
+            let offset = input[..input.len() - start.len()].chars().count();
+            (start.trim_end(), offset)
+        } else {
+            (input, 0)
+        };
+
+        let mut has_digit = false;
+        for (index, ch) in trimmed.chars().enumerate() {
+            if self.separators.contains(&ch) {
+                continue;
+            }
+            if self.digit(ch).is_none() {
+                return Err(VerhoeffError::InvalidCharacter {
+                    ch,
+                    index: index + offset,
+                });
+            }
+            has_digit = true;
+        }
+
+        if has_digit {
+            Ok(trimmed)
+        } else {
+            Err(no_digits(input))
+        }
+    }
+
+    /// Iterates the digit values of checked input in reverse, skipping
+    /// separators even when they are digits themselves
+    fn digits_rev<'s>(&'s self, checked: &'s str) -> impl Iterator<Item = u8> + 's {
+        checked
+            .chars()
+            .rev()
+            .filter(|ch| !self.separators.contains(ch))
+            .filter_map(|ch| self.digit(ch))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{calculate_checksum, validate_result, validate_with_separators};
+
+    #[test]
+    fn test_default_matches_strict_api() {
+        let options = ValidateOptions::default();
+        assert_eq!(options, ValidateOptions::new());
+
+        for input in ["123451", "123452", "", "12 34", "१२३"] {
+            assert_eq!(options.validate(input), validate_result(input), "{input}");
+        }
+        assert_eq!(
+            options.calculate_checksum("12345"),
+            Ok(calculate_checksum("12345"))
+        );
+    }
+
+    #[test]
+    fn test_allow_separators() {
+        let options = ValidateOptions::new().allow_separators(&['-']);
+        assert_eq!(options.validate("1234-5678-9010"), Ok(true));
+        assert_eq!(options.calculate_checksum("-1-2-3-4-5-"), Ok(1));
+        assert_eq!(
+            options.validate("---"),
+            Err(VerhoeffError::EmptyAfterNormalization)
+        );
+        assert_eq!(options.validate(""), Err(VerhoeffError::EmptyInput));
+        assert_eq!(
+            options.validate("1234 5678"),
+            Err(VerhoeffError::InvalidCharacter { ch: ' ', index: 4 })
+        );
+    }
+
+    #[test]
+    fn test_digit_separator_matches_validate_with_separators() {
+        let options = ValidateOptions::new().allow_separators(&['0']);
+        for input in ["1230", "2363", "203603", "12340", "00"] {
+            assert_eq!(
+                options.validate(input),
+                validate_with_separators(input, &['0']),
+                "{input}"
+            );
+        }
+        assert_eq!(options.validate("20360"), validate_result("236"));
+        assert_eq!(
+            options.calculate_checksum("10203"),
+            Ok(calculate_checksum("123"))
+        );
+    }
+
+    #[test]
+    fn test_allow_unicode_digits() {
+        let options = ValidateOptions::new().allow_unicode_digits(true);
+        assert_eq!(options.validate("१२३४५१"), Ok(true));
+        assert_eq!(options.calculate_checksum("١٢٣٤٥"), Ok(1));
+        assert_eq!(
+            options.validate("12½"),
+            Err(VerhoeffError::InvalidCharacter { ch: '½', index: 2 })
+        );
+        assert!(ValidateOptions::new()
+            .allow_unicode_digits(false)
+            .validate("१२३४५१")
+            .is_err());
+    }
+
+    #[test]
+    fn test_trim_whitespace() {
+        let options = ValidateOptions::new().trim_whitespace(true);
+        assert_eq!(options.validate("\t123451\n"), Ok(true));
+        assert_eq!(
+            options.validate("   "),
+            Err(VerhoeffError::EmptyAfterNormalization)
+        );
+
+        // Inner whitespace is still rejected, at its original position
+        assert_eq!(
+            options.validate("  123 451"),
+            Err(VerhoeffError::InvalidCharacter { ch: ' ', index: 5 })
+        );
+    }
+
+    #[test]
+    fn test_combined_options() {
+        let options = ValidateOptions::new()
+            .allow_separators(&[' '])
+            .allow_unicode_digits(true)
+            .trim_whitespace(true);
+
+        assert_eq!(options.validate(" १२३४ ५६७८ ९०१० "), Ok(true));
+        assert_eq!(options.calculate_checksum("1234 ५६७८ 901 "), Ok(0));
+        assert_eq!(
+            options.validate(" १२३४_५६७८"),
+            Err(VerhoeffError::InvalidCharacter { ch: '_', index: 5 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_append_checksum() {
+        assert_eq!(
+            ValidateOptions::new().append_checksum("12345"),
+            Ok("123451".into())
+        );
+
+        let options = ValidateOptions::new()
+            .allow_separators(&['-'])
+            .trim_whitespace(true);
+        assert_eq!(options.append_checksum(" 12-345 "), Ok("12-3451".into()));
+        assert_eq!(
+            options.append_checksum(" "),
+            Err(VerhoeffError::EmptyAfterNormalization)
+        );
+    }
+}
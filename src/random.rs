@@ -0,0 +1,124 @@
+// FilePath: src/random.rs
+
+//! Generation of random, checksum-valid numbers
+//!
+//! The numbers produced here are synthetic: they satisfy the Verhoeff check
+//! and, for `random_aadhaar`, the 12-digit length and the 2-9 leading digit,
+//! but they are not real issued identifiers and must not be treated as such.
+
+use alloc::string::String;
+
+use rand::Rng;
+
+use crate::{accumulate, INV_TABLE};
+
+/// Generate `base_len` random digits followed by their Verhoeff check digit.
+///
+/// The result always passes [`validate`](crate::validate) and is
+/// `base_len + 1` digits long. The numbers are synthetic, not real IDs.
+///
+/// # Example
+///
+/// ```
+/// use rand::SeedableRng;
+/// use verhoeff::{random_number, validate};
+///
+/// let mut rng = rand::rngs::SmallRng::seed_from_u64(7);
+/// let number = random_number(&mut rng, 15);
+/// assert_eq!(number.len(), 16);
+/// assert!(validate(&number));
+/// ```
+pub fn random_number<R: Rng + ?Sized>(rng: &mut R, base_len: usize) -> String {
+    let mut number = String::with_capacity(base_len + 1);
+    push_random_digits(rng, &mut number, base_len);
+    push_check_digit(&mut number);
+    number
+}
+
+/// Generate a synthetic 12-digit Aadhaar-format number with a valid check
+/// digit.
+///
+/// Like an issued Aadhaar number, the result never starts with 0 or 1, so
+/// it passes [`validate_aadhaar_strict`](crate::validate_aadhaar_strict) as
+/// well as [`validate_aadhaar`](crate::validate_aadhaar). It is not a real
+/// issued Aadhaar number.
+#[cfg(feature = "aadhaar")]
+pub fn random_aadhaar<R: Rng + ?Sized>(rng: &mut R) -> String {
+    let mut number = String::with_capacity(12);
+    number.push(char::from(b'0' + rng.random_range(2..10u8)));
+    push_random_digits(rng, &mut number, 10);
+    push_check_digit(&mut number);
+    number
+}
+
+/// Appends `count` uniformly random digits to `number`
+fn push_random_digits<R: Rng + ?Sized>(rng: &mut R, number: &mut String, count: usize) {
+    for _ in 0..count {
+        number.push(char::from(b'0' + rng.random_range(0..10u8)));
+    }
+}
+
+/// Appends the Verhoeff check digit of the all-digit `number`
+fn push_check_digit(number: &mut String) {
+    let c = accumulate(number.bytes().rev().map(|b| b - b'0'), 1, 0);
+    number.push(char::from(b'0' + INV_TABLE[c as usize]));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validate;
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_random_numbers_validate() {
+        let mut rng = SmallRng::seed_from_u64(42);
+
+        for i in 0..1000 {
+            let base_len = 1 + i % 40;
+            let number = random_number(&mut rng, base_len);
+            assert_eq!(number.len(), base_len + 1);
+            assert!(validate(&number), "Generated invalid number {number}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "aadhaar")]
+    fn test_random_aadhaar_validates() {
+        let mut rng = SmallRng::seed_from_u64(1234);
+
+        for _ in 0..1000 {
+            let aadhaar = random_aadhaar(&mut rng);
+            assert_eq!(crate::validate_aadhaar(&aadhaar), Ok(true), "{aadhaar}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "aadhaar")]
+    fn test_random_aadhaar_passes_strict_validation() {
+        let mut rng = SmallRng::seed_from_u64(99);
+
+        let mut leading = [false; 10];
+        for _ in 0..1000 {
+            let aadhaar = random_aadhaar(&mut rng);
+            assert_eq!(
+                crate::validate_aadhaar_strict(&aadhaar),
+                Ok(true),
+                "{aadhaar}"
+            );
+            leading[(aadhaar.as_bytes()[0] - b'0') as usize] = true;
+        }
+        // Every allowed leading digit occurs, and only those
+        assert_eq!(
+            leading,
+            [false, false, true, true, true, true, true, true, true, true]
+        );
+    }
+
+    #[test]
+    fn test_empty_base() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        assert_eq!(random_number(&mut rng, 0), "0");
+    }
+}
@@ -0,0 +1,288 @@
+// FilePath: src/reader.rs
+
+//! Streaming validation of one number per line from a reader
+
+use std::io::{self, BufRead};
+#[cfg(feature = "futures")]
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+#[cfg(feature = "futures")]
+use futures_core::Stream;
+#[cfg(feature = "tokio")]
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, Lines};
+
+use crate::{validate_result, Result, VerhoeffError};
+
+/// Validate every line of `reader`, yielding one result per line.
+///
+/// Lines are read lazily, so arbitrarily large inputs are processed in
+/// constant memory. The line terminator (`\n` or `\r\n`) is removed, but
+/// nothing else is trimmed: any other non-digit, including surrounding
+/// whitespace, is reported as `VerhoeffError::InvalidCharacter`. A blank
+/// line yields `VerhoeffError::EmptyInput`, and a line that cannot be read,
+/// for example because it is not valid UTF-8, yields `VerhoeffError::Io`.
+/// Iteration continues after an error.
+///
+/// # Example
+///
+/// ```
+/// use std::io::Cursor;
+/// use verhoeff::{validate_reader, VerhoeffError};
+///
+/// let input = Cursor::new("123451\n123452\n\n");
+/// let results: Vec<_> = validate_reader(input).collect();
+/// assert_eq!(results, vec![Ok(true), Ok(false), Err(VerhoeffError::EmptyInput)]);
+/// ```
+pub fn validate_reader<R: BufRead>(reader: R) -> impl Iterator<Item = Result<bool>> {
+    reader.lines().map(validate_line)
+}
+
+/// Validate every line of an asynchronous stream of lines, yielding one
+/// result per line.
+///
+/// The runtime-agnostic building block of `validate_async`, enabled by the
+/// `futures` feature: `lines` is any [`Stream`] of lines with their
+/// terminators already removed, such as `futures::AsyncBufReadExt::lines`
+/// produces. Each line is handled exactly as by [`validate_reader`]:
+/// nothing is trimmed, a blank line yields `VerhoeffError::EmptyInput`, a
+/// read error yields `VerhoeffError::Io`, and iteration continues after an
+/// error.
+///
+/// # Example
+///
+/// ```
+/// use tokio::io::{AsyncBufReadExt, BufReader};
+/// use tokio_stream::{wrappers::LinesStream, StreamExt};
+/// use verhoeff::VerhoeffError;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let input: &[u8] = b"123451\n123452\n\n";
+/// let lines = LinesStream::new(BufReader::new(input).lines());
+/// let results: Vec<_> = verhoeff::validate_stream(lines).collect().await;
+/// assert_eq!(results, vec![Ok(true), Ok(false), Err(VerhoeffError::EmptyInput)]);
+/// # }
+/// ```
+#[cfg(feature = "futures")]
+pub fn validate_stream<S>(lines: S) -> impl Stream<Item = Result<bool>>
+where
+    S: Stream<Item = io::Result<String>> + Unpin,
+{
+    ValidateStream { lines }
+}
+
+/// The stream returned by [`validate_stream`]
+#[cfg(feature = "futures")]
+struct ValidateStream<S> {
+    lines: S,
+}
+
+#[cfg(feature = "futures")]
+impl<S> Stream for ValidateStream<S>
+where
+    S: Stream<Item = io::Result<String>> + Unpin,
+{
+    type Item = Result<bool>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.lines)
+            .poll_next(cx)
+            .map(|line| line.map(validate_line))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lines.size_hint()
+    }
+}
+
+/// Validate every line of a tokio `AsyncBufRead`, yielding one result per
+/// line.
+///
+/// This is the async counterpart of [`validate_reader`], enabled by the
+/// `tokio` feature. The reader is split into lines internally and each line
+/// is handled exactly as by [`validate_reader`]: the terminator is removed
+/// but nothing else is trimmed, a blank line yields
+/// `VerhoeffError::EmptyInput`, a line that cannot be read yields
+/// `VerhoeffError::Io`, and iteration continues after an error.
+///
+/// # Example
+///
+/// ```
+/// use tokio::io::BufReader;
+/// use tokio_stream::StreamExt;
+/// use verhoeff::{validate_async, VerhoeffError};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let input: &[u8] = b"123451\n123452\n\n";
+/// let results: Vec<_> = validate_async(BufReader::new(input)).collect().await;
+/// assert_eq!(results, vec![Ok(true), Ok(false), Err(VerhoeffError::EmptyInput)]);
+/// # }
+/// ```
+#[cfg(feature = "tokio")]
+pub fn validate_async<R>(reader: R) -> impl Stream<Item = Result<bool>>
+where
+    R: AsyncBufRead + Unpin,
+{
+    validate_stream(LineStream {
+        lines: reader.lines(),
+    })
+}
+
+/// Adapts tokio's `Lines` to a [`Stream`] for [`validate_stream`]
+#[cfg(feature = "tokio")]
+struct LineStream<R> {
+    lines: Lines<R>,
+}
+
+#[cfg(feature = "tokio")]
+impl<R: AsyncBufRead + Unpin> Stream for LineStream<R> {
+    type Item = io::Result<String>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.lines)
+            .poll_next_line(cx)
+            .map(io::Result::transpose)
+    }
+}
+
+/// Validates one line read from a reader or stream
+fn validate_line(line: io::Result<String>) -> Result<bool> {
+    match line {
+        Ok(line) => validate_result(&line),
+        Err(_) => Err(VerhoeffError::Io),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_validate_reader() {
+        let input = "123456789010\n123456789013\r\n12a45\n\n 2363\n2363";
+        let results: Vec<_> = validate_reader(Cursor::new(input)).collect();
+
+        assert_eq!(
+            results,
+            vec![
+                Ok(true),
+                Ok(false),
+                Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 2 }),
+                Err(VerhoeffError::EmptyInput),
+                Err(VerhoeffError::InvalidCharacter { ch: ' ', index: 0 }),
+                Ok(true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_reader_edges() {
+        assert_eq!(validate_reader(Cursor::new("")).count(), 0);
+
+        // A trailing newline does not produce an extra blank line
+        let results: Vec<_> = validate_reader(Cursor::new("2363\n")).collect();
+        assert_eq!(results, vec![Ok(true)]);
+
+        // Unreadable lines are reported and iteration continues
+        let input: &[u8] = b"2363\n\xFF\n2363\n";
+        let results: Vec<_> = validate_reader(input).collect();
+        assert_eq!(results, vec![Ok(true), Err(VerhoeffError::Io), Ok(true)]);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_validate_async() {
+        use tokio::io::BufReader;
+        use tokio_stream::StreamExt;
+
+        let input: &[u8] = b"123456789010\n123456789013\r\n12a45\n\n 2363\n2363";
+        let results: Vec<_> = validate_async(BufReader::new(input)).collect().await;
+
+        assert_eq!(
+            results,
+            vec![
+                Ok(true),
+                Ok(false),
+                Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 2 }),
+                Err(VerhoeffError::EmptyInput),
+                Err(VerhoeffError::InvalidCharacter { ch: ' ', index: 0 }),
+                Ok(true),
+            ]
+        );
+
+        // Unreadable lines are reported and iteration continues
+        let input: &[u8] = b"2363\n\xFF\n2363\n";
+        let results: Vec<_> = validate_async(BufReader::new(input)).collect().await;
+        assert_eq!(results, vec![Ok(true), Err(VerhoeffError::Io), Ok(true)]);
+    }
+
+    /// An in-memory async line source that is pending before every item, to
+    /// exercise the pending path
+    #[cfg(feature = "futures")]
+    struct MemoryLines {
+        lines: std::vec::IntoIter<io::Result<String>>,
+        ready: bool,
+    }
+
+    #[cfg(feature = "futures")]
+    impl Stream for MemoryLines {
+        type Item = io::Result<String>;
+
+        fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            self.ready = !self.ready;
+            if self.ready {
+                Poll::Ready(self.lines.next())
+            } else {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    fn test_validate_stream() {
+        let lines = vec![
+            Ok("123456789010".to_string()),
+            Ok("123456789013".to_string()),
+            Ok("12a45".to_string()),
+            Ok(String::new()),
+            Err(io::Error::new(io::ErrorKind::InvalidData, "not UTF-8")),
+            Ok("2363".to_string()),
+        ];
+        let mut stream = validate_stream(MemoryLines {
+            lines: lines.into_iter(),
+            ready: true,
+        });
+
+        let mut cx = Context::from_waker(std::task::Waker::noop());
+        let mut results = Vec::new();
+        let mut pending = 0;
+        loop {
+            match Pin::new(&mut stream).poll_next(&mut cx) {
+                Poll::Ready(Some(result)) => results.push(result),
+                Poll::Ready(None) => break,
+                Poll::Pending => pending += 1,
+            }
+        }
+
+        assert_eq!(
+            results,
+            vec![
+                Ok(true),
+                Ok(false),
+                Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 2 }),
+                Err(VerhoeffError::EmptyInput),
+                Err(VerhoeffError::Io),
+                Ok(true),
+            ]
+        );
+        // One pending poll before each of the six lines and the end
+        assert_eq!(pending, 7);
+    }
+}
@@ -0,0 +1,211 @@
+// FilePath: src/report.rs
+
+//! A human-readable validation report for one number
+
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use crate::{
+    accumulate, breakdown, digits_rev, nearest_valid_by_single_substitution, VerhoeffError,
+    INV_TABLE,
+};
+
+/// The outcome of [`report`], formatted for people by its `Display` impl.
+///
+/// The report shows the base, the check digit as written and as computed,
+/// whether they agree, and for an invalid number every valid number one
+/// digit substitution away. Malformed input still produces a report, which
+/// shows the error instead.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::report;
+///
+/// let text = report("123491").to_string();
+/// assert!(text.contains("Provided:    1"));
+/// assert!(text.contains("Computed:    8"));
+/// assert!(text.contains("123451  position 4: 9 -> 5"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report<'a> {
+    input: &'a str,
+    details: Result<Details<'a>, VerhoeffError>,
+}
+
+/// The parts of a well-formed report
+#[derive(Debug, Clone, PartialEq)]
+struct Details<'a> {
+    base: &'a str,
+    provided: u8,
+    computed: u8,
+    corrections: Vec<String>,
+}
+
+impl Report<'_> {
+    /// The input the report describes.
+    pub fn input(&self) -> &str {
+        self.input
+    }
+
+    /// Whether the input is well-formed and its check digit is correct.
+    pub fn is_valid(&self) -> bool {
+        matches!(&self.details, Ok(d) if d.provided == d.computed)
+    }
+
+    /// Why the input could not be checked, if it is malformed.
+    pub fn error(&self) -> Option<&VerhoeffError> {
+        self.details.as_ref().err()
+    }
+
+    /// The valid numbers one digit substitution away, ordered by position.
+    ///
+    /// Empty for valid or malformed input.
+    pub fn corrections(&self) -> &[String] {
+        self.details.as_ref().map_or(&[], |d| &d.corrections)
+    }
+}
+
+impl fmt::Display for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Number:      {}", self.input)?;
+        let details = match &self.details {
+            Ok(details) => details,
+            Err(e) => return write!(f, "Error:       {e}"),
+        };
+
+        let base = if details.base.is_empty() {
+            "(none)"
+        } else {
+            details.base
+        };
+        writeln!(f, "Base:        {base}")?;
+        writeln!(f, "Provided:    {}", details.provided)?;
+        writeln!(f, "Computed:    {}", details.computed)?;
+        if details.provided == details.computed {
+            return write!(f, "Valid:       yes");
+        }
+
+        write!(f, "Valid:       no\nCorrections:")?;
+        for corrected in &details.corrections {
+            // Each correction differs from the input in exactly one digit
+            let (position, (was, now)) = self
+                .input
+                .bytes()
+                .zip(corrected.bytes())
+                .enumerate()
+                .find(|(_, (was, now))| was != now)
+                .expect("a correction changes one digit");
+            write!(
+                f,
+                "\n  {corrected}  position {position}: {} -> {}",
+                char::from(was),
+                char::from(now)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Build a validation report for a full number, including its check digit.
+///
+/// This never fails: malformed input yields a report that carries the error.
+/// Costs one validation plus, for an invalid number, the O(len * 10) search
+/// of [`nearest_valid_by_single_substitution`].
+///
+/// # Arguments
+///
+/// * `input` - The full number to report on
+///
+/// # Returns
+///
+/// * `Report` - The report; format it with `{}`
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::report;
+///
+/// let r = report("123451");
+/// assert!(r.is_valid());
+/// println!("{r}");
+///
+/// assert!(report("12a451").error().is_some());
+/// ```
+pub fn report(input: &str) -> Report<'_> {
+    let details = breakdown(input).and_then(|parts| {
+        let corrections = if parts.valid {
+            Vec::new()
+        } else {
+            nearest_valid_by_single_substitution(input)?
+        };
+        Ok(Details {
+            base: parts.base,
+            provided: parts.check_digit,
+            computed: INV_TABLE[accumulate(digits_rev(parts.base), 1, 0) as usize],
+            corrections,
+        })
+    });
+    Report { input, details }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_valid_report() {
+        let r = report("123451");
+        assert!(r.is_valid());
+        assert!(r.corrections().is_empty());
+
+        let text = r.to_string();
+        assert_eq!(
+            text,
+            "Number:      123451\n\
+             Base:        12345\n\
+             Provided:    1\n\
+             Computed:    1\n\
+             Valid:       yes"
+        );
+    }
+
+    #[test]
+    fn test_invalid_report() {
+        let r = report("123491");
+        assert!(!r.is_valid());
+        assert_eq!(r.corrections().len(), 6);
+
+        let text = r.to_string();
+        for expected in [
+            "Base:        12349",
+            "Provided:    1",
+            "Computed:    8",
+            "Valid:       no",
+            "Corrections:",
+            "123451  position 4: 9 -> 5",
+        ] {
+            assert!(text.contains(expected), "missing {expected:?} in:\n{text}");
+        }
+        assert_eq!(text.lines().count(), 6 + 6);
+    }
+
+    #[test]
+    fn test_single_digit_and_malformed_reports() {
+        let text = report("0").to_string();
+        assert!(text.contains("Base:        (none)"), "{text}");
+        assert!(text.contains("Computed:    0"), "{text}");
+
+        let r = report("12a4");
+        assert!(!r.is_valid());
+        assert_eq!(
+            r.error(),
+            Some(&VerhoeffError::InvalidCharacter { ch: 'a', index: 2 })
+        );
+        assert_eq!(
+            r.to_string(),
+            "Number:      12a4\nError:       Invalid character 'a' at position 2 - only digits allowed"
+        );
+    }
+}
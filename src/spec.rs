@@ -0,0 +1,105 @@
+// FilePath: src/spec.rs
+
+//! Named fixed-length identifier schemes
+
+use crate::{validate_fixed_length, Result, VerhoeffError};
+
+/// A named identifier scheme: a fixed number of digits ending in a Verhoeff
+/// check digit.
+///
+/// Defining a spec once replaces a hand-written validator per ID type, and
+/// its length errors carry the spec's name so they read well in messages.
+/// The Aadhaar and Virtual ID rules are available as `AADHAAR` and `VID`
+/// with the `aadhaar` feature.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{IdSpec, VerhoeffError};
+///
+/// const DEVICE_ID: IdSpec = IdSpec::new("Device ID", 10);
+///
+/// assert_eq!(DEVICE_ID.validate("8473643095"), Ok(true));
+///
+/// let err = DEVICE_ID.validate("84736430952").unwrap_err();
+/// assert_eq!(err.to_string(), "Device ID must be 10 digits, got 11 digits");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IdSpec {
+    name: &'static str,
+    total_len: usize,
+}
+
+impl IdSpec {
+    /// Create a spec for `name`, with `total_len` digits including the
+    /// check digit.
+    pub const fn new(name: &'static str, total_len: usize) -> Self {
+        IdSpec { name, total_len }
+    }
+
+    /// The name used in error messages.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The required number of digits, including the check digit.
+    pub const fn total_len(&self) -> usize {
+        self.total_len
+    }
+
+    /// Validate `input` against this spec.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` - If the length is right and the checksum is valid
+    /// * `Ok(false)` - If the length is right but the checksum is invalid
+    /// * `Err(VerhoeffError::InvalidIdLength)` - If the length is wrong
+    /// * `Err(VerhoeffError)` - If the input is empty or contains non-digits
+    pub fn validate(&self, input: &str) -> Result<bool> {
+        validate_fixed_length(input, self.total_len).map_err(|e| match e {
+            VerhoeffError::InvalidLength { expected, got } => VerhoeffError::InvalidIdLength {
+                name: self.name,
+                expected,
+                got,
+            },
+            e => e,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEVICE_ID: IdSpec = IdSpec::new("Device ID", 10);
+
+    #[test]
+    fn test_custom_spec() {
+        assert_eq!(DEVICE_ID.name(), "Device ID");
+        assert_eq!(DEVICE_ID.total_len(), 10);
+
+        assert_eq!(DEVICE_ID.validate("8473643095"), Ok(true));
+        assert_eq!(DEVICE_ID.validate("8473643096"), Ok(false));
+        assert_eq!(
+            DEVICE_ID.validate("847364309a"),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 9 })
+        );
+    }
+
+    #[test]
+    fn test_length_error_is_named() {
+        let err = DEVICE_ID.validate("84736430952").unwrap_err();
+        assert_eq!(
+            err,
+            VerhoeffError::InvalidIdLength {
+                name: "Device ID",
+                expected: 10,
+                got: 11
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "Device ID must be 10 digits, got 11 digits"
+        );
+    }
+}
@@ -0,0 +1,440 @@
+// FilePath: src/state.rs
+
+//! Incremental (streaming) Verhoeff computation
+//!
+//! The one-shot functions walk the digits right to left, so the permutation
+//! row applied to a digit depends on its distance from the *end* of the
+//! number. A stream arrives left to right and its length is not known in
+//! advance, so [`VerhoeffState`] cannot know which row a digit needs at the
+//! moment it arrives.
+//!
+//! ## Forward iteration
+//!
+//! Write `p_k` for `P_TABLE[k % 8]` and `·` for the `D_TABLE` group
+//! operation. For digits `a_0 .. a_{n-1}` the checksum loop computes
+//!
+//! ```text
+//! C = p_1(a_{n-1}) · p_2(a_{n-2}) · ... · p_n(a_0)
+//! ```
+//!
+//! and the check digit is `INV_TABLE[C]`. Because the permutation rows repeat
+//! with period 8, the state keeps eight accumulators, one per possible row
+//! offset:
+//!
+//! ```text
+//! S_s = p_{1+s}(a_{m-1}) · p_{2+s}(a_{m-2}) · ... · p_{m+s}(a_0)
+//! ```
+//!
+//! When digit `a_m` arrives it becomes the rightmost digit, every earlier
+//! digit moves one position further from the end, and the product gains a
+//! new leftmost factor:
+//!
+//! ```text
+//! S'_s = p_{1+s}(a_m) · S_{s+1}
+//! ```
+//!
+//! This only ever *prepends* to the product, so the non-commutativity of D₅
+//! is respected. At the end `S_0` is the checksum accumulator `C`, and
+//! `S_7` (row offset `-1`, i.e. the rightmost digit uses row 0) is the
+//! accumulator of the validation loop, which is zero for a valid number.
+
+use crate::{Result, VerhoeffError, D_TABLE, INV_TABLE, P_TABLE};
+
+/// Streaming Verhoeff state that accepts digits left to right in chunks.
+///
+/// Feeding a number through any sequence of [`update`](Self::update) calls
+/// yields the same results as the one-shot functions on the concatenation.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{calculate_checksum, VerhoeffState};
+///
+/// let mut state = VerhoeffState::new();
+/// state.update(&[1, 2, 3]);
+/// state.update(&[4, 5]);
+/// assert_eq!(state.finalize_checksum(), calculate_checksum("12345"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VerhoeffState {
+    /// One accumulator per permutation row offset (see module docs)
+    acc: [u8; 8],
+    /// Number of digits processed so far
+    len: usize,
+}
+
+impl VerhoeffState {
+    /// Create an empty state.
+    pub const fn new() -> Self {
+        VerhoeffState {
+            acc: [0; 8],
+            len: 0,
+        }
+    }
+
+    /// Feed the next chunk of digit values (0-9), continuing to the right of
+    /// all digits fed so far.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any value is greater than 9.
+    pub fn update(&mut self, digits: &[u8]) {
+        for &digit in digits {
+            self.push(digit);
+        }
+    }
+
+    /// Feed the next chunk of raw digit values (0-9).
+    ///
+    /// This is the [`Hasher::write`](core::hash::Hasher::write)-style name for
+    /// [`update`](Self::update), for framed protocols that hand over chunks
+    /// as they arrive. Note that Verhoeff is an error-detecting code, not a
+    /// hash: it offers no resistance to deliberate tampering.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any value is greater than 9.
+    pub fn write_digits(&mut self, digits: &[u8]) {
+        self.update(digits);
+    }
+
+    /// Feed the next chunk of ASCII digits (`b'0'..=b'9'`).
+    ///
+    /// The chunk is checked before any of it is fed, so on error the state
+    /// is unchanged and the caller may resume with corrected data.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If every byte was an ASCII digit
+    /// * `Err(VerhoeffError::InvalidCharacter)` - For the first other byte; its
+    ///   `index` counts from the start of the whole stream, and a non-ASCII
+    ///   byte is reported as `char::REPLACEMENT_CHARACTER`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use verhoeff::VerhoeffState;
+    ///
+    /// let mut state = VerhoeffState::new();
+    /// state.write_ascii(b"123")?;
+    /// state.write_ascii(b"45")?;
+    /// assert_eq!(state.finalize_checksum(), 1);
+    /// # Ok::<(), verhoeff::VerhoeffError>(())
+    /// ```
+    pub fn write_ascii(&mut self, bytes: &[u8]) -> Result<()> {
+        if let Some(pos) = bytes.iter().position(|b| !b.is_ascii_digit()) {
+            let b = bytes[pos];
+            let ch = if b.is_ascii() {
+                char::from(b)
+            } else {
+                char::REPLACEMENT_CHARACTER
+            };
+            return Err(VerhoeffError::InvalidCharacter {
+                ch,
+                index: self.len + pos,
+            });
+        }
+
+        for &b in bytes {
+            self.push(b - b'0');
+        }
+        Ok(())
+    }
+
+    /// Number of digits processed so far.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether no digits have been processed yet.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The running check value of the digits fed so far, as returned by
+    /// [`running_check`](crate::running_check): 0 exactly when they form a
+    /// valid number ending in its check digit.
+    ///
+    /// Together with [`checksum`](Self::checksum) this lets a UI give
+    /// feedback on every keystroke: the state is `Copy`, so keep one per
+    /// accepted prefix and branch from it as the input changes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use verhoeff::VerhoeffState;
+    ///
+    /// let mut typed = VerhoeffState::new();
+    /// typed.update(&[1, 2, 3, 4, 5]);
+    /// assert_eq!(typed.checksum(), 1);
+    ///
+    /// // Try a candidate final keystroke without losing the prefix
+    /// let mut attempt = typed;
+    /// attempt.update(&[1]);
+    /// assert_eq!(attempt.check_value(), 0);
+    /// assert_ne!(typed.check_value(), 0);
+    /// ```
+    pub const fn check_value(&self) -> u8 {
+        self.acc[7]
+    }
+
+    /// The check digit to append to the digits fed so far, without
+    /// consuming the state.
+    pub const fn checksum(&self) -> u8 {
+        INV_TABLE[self.acc[0] as usize]
+    }
+
+    /// Whether the digits fed so far form a valid number, without consuming
+    /// the state.
+    pub const fn is_valid(&self) -> bool {
+        self.len > 0 && self.acc[7] == 0
+    }
+
+    /// Consume the state and return the check digit to append to the digits
+    /// fed so far.
+    ///
+    /// An empty state yields 0, matching [`calculate_checksum`](crate::calculate_checksum).
+    pub fn finalize_checksum(self) -> u8 {
+        self.checksum()
+    }
+
+    /// Consume the state and report whether the digits fed so far, taken as
+    /// a full number ending in its check digit, are valid.
+    ///
+    /// An empty state is never valid, matching [`validate`](crate::validate).
+    pub fn finalize_validate(self) -> bool {
+        self.is_valid()
+    }
+
+    fn push(&mut self, digit: u8) {
+        assert!(digit <= 9, "digit value {digit} out of range 0-9");
+
+        let prev = self.acc;
+        for (s, acc) in self.acc.iter_mut().enumerate() {
+            let permuted = P_TABLE[(s + 1) % 8][digit as usize];
+            *acc = D_TABLE[permuted as usize][prev[(s + 1) % 8] as usize];
+        }
+        self.len += 1;
+    }
+}
+
+impl Default for VerhoeffState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Feeds an iterator of digit values into a fresh state, rejecting values
+/// above 9 and empty input
+fn state_from_iter<I: IntoIterator<Item = u8>>(digits: I) -> Result<VerhoeffState> {
+    let mut state = VerhoeffState::new();
+    for digit in digits {
+        if digit > 9 {
+            return Err(VerhoeffError::InvalidDigitValue(digit));
+        }
+        state.push(digit);
+    }
+
+    if state.is_empty() {
+        Err(VerhoeffError::EmptyInput)
+    } else {
+        Ok(state)
+    }
+}
+
+/// Calculate the check digit of a sequence of digit values (0-9), most
+/// significant first.
+///
+/// Any `IntoIterator` is accepted: the digits are consumed once, left to
+/// right, through a [`VerhoeffState`], so the iterator needs neither a known
+/// length nor reverse iteration and nothing is buffered.
+///
+/// # Returns
+///
+/// * `Ok(u8)` - The check digit
+/// * `Err(VerhoeffError::InvalidDigitValue)` - If a value is greater than 9
+/// * `Err(VerhoeffError::EmptyInput)` - If the iterator yields nothing
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::calculate_checksum_iter;
+///
+/// let digits = b"12345".iter().map(|b| b - b'0');
+/// assert_eq!(calculate_checksum_iter(digits), Ok(1));
+/// ```
+pub fn calculate_checksum_iter<I: IntoIterator<Item = u8>>(digits: I) -> Result<u8> {
+    state_from_iter(digits).map(VerhoeffState::finalize_checksum)
+}
+
+/// Validate a sequence of digit values (0-9) ending in its check digit.
+///
+/// Follows the same rules as [`calculate_checksum_iter`].
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::validate_iter;
+///
+/// assert_eq!(validate_iter([1, 2, 3, 4, 5, 1]), Ok(true));
+/// assert_eq!(validate_iter([1, 2, 3, 4, 5, 2]), Ok(false));
+/// ```
+pub fn validate_iter<I: IntoIterator<Item = u8>>(digits: I) -> Result<bool> {
+    state_from_iter(digits).map(VerhoeffState::finalize_validate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{calculate_checksum, validate};
+
+    fn to_digits(s: &str) -> Vec<u8> {
+        s.bytes().map(|b| b - b'0').collect()
+    }
+
+    #[test]
+    fn test_chunked_checksum_matches_one_shot() {
+        let inputs = ["236", "12345", "142857", "12345678901", "8473643095"];
+
+        for input in inputs {
+            let digits = to_digits(input);
+            let expected = calculate_checksum(input);
+
+            for chunk_size in 1..=digits.len() {
+                let mut state = VerhoeffState::new();
+                for chunk in digits.chunks(chunk_size) {
+                    state.update(chunk);
+                }
+                assert_eq!(
+                    state.finalize_checksum(),
+                    expected,
+                    "Mismatch for '{input}' with chunk size {chunk_size}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_long_stream_crosses_permutation_period() {
+        let base = "9876543210".repeat(17);
+        let mut state = VerhoeffState::new();
+        for chunk in to_digits(&base).chunks(7) {
+            state.update(chunk);
+        }
+        assert_eq!(state.len(), base.len());
+        assert_eq!(state.finalize_checksum(), calculate_checksum(&base));
+    }
+
+    #[test]
+    fn test_chunked_validate_matches_one_shot() {
+        for input in ["2363", "2364", "123451", "123450", "0", "5"] {
+            let mut state = VerhoeffState::new();
+            for chunk in to_digits(input).chunks(2) {
+                state.update(chunk);
+            }
+            assert_eq!(state.finalize_validate(), validate(input), "'{input}'");
+        }
+    }
+
+    #[test]
+    fn test_check_value_per_keystroke() {
+        let input = "8473643095";
+        let full = format!("{input}{}", calculate_checksum(input));
+
+        let mut state = VerhoeffState::new();
+        for (i, key) in full.bytes().enumerate() {
+            state.write_ascii(&[key]).unwrap();
+            let prefix = &full[..=i];
+            assert_eq!(Ok(state.check_value()), crate::running_check(prefix));
+            assert_eq!(state.is_valid(), validate(prefix), "'{prefix}'");
+            assert_eq!(state.checksum(), calculate_checksum(prefix));
+        }
+        assert!(state.is_valid());
+    }
+
+    #[test]
+    fn test_empty_state() {
+        assert!(VerhoeffState::new().is_empty());
+        assert_eq!(VerhoeffState::new().finalize_checksum(), 0);
+        assert!(!VerhoeffState::default().finalize_validate());
+    }
+
+    #[test]
+    fn test_write_split_at_every_boundary() {
+        let input = "12345678901234567";
+        let expected = calculate_checksum(input);
+        let bytes = input.as_bytes();
+        let digits = to_digits(input);
+
+        for split in 0..=bytes.len() {
+            let mut ascii = VerhoeffState::new();
+            ascii.write_ascii(&bytes[..split]).unwrap();
+            ascii.write_ascii(&bytes[split..]).unwrap();
+
+            let mut raw = VerhoeffState::new();
+            raw.write_digits(&digits[..split]);
+            raw.write_digits(&digits[split..]);
+
+            assert_eq!(ascii, raw, "split at {split}");
+            assert_eq!(ascii.finalize_checksum(), expected, "split at {split}");
+        }
+    }
+
+    #[test]
+    fn test_write_ascii_rejects_without_feeding() {
+        let mut state = VerhoeffState::new();
+        state.write_ascii(b"123").unwrap();
+        let before = state;
+
+        assert_eq!(
+            state.write_ascii(b"45x"),
+            Err(VerhoeffError::InvalidCharacter { ch: 'x', index: 5 })
+        );
+        assert_eq!(
+            state.write_ascii(&[b'4', 0xE0]),
+            Err(VerhoeffError::InvalidCharacter {
+                ch: char::REPLACEMENT_CHARACTER,
+                index: 4
+            })
+        );
+        assert_eq!(state, before);
+
+        state.write_ascii(b"45").unwrap();
+        assert_eq!(state.finalize_checksum(), 1);
+    }
+
+    #[test]
+    fn test_iter_matches_string_api() {
+        assert_eq!(
+            calculate_checksum_iter(0..=9),
+            Ok(calculate_checksum("0123456789"))
+        );
+        assert_eq!(
+            validate_iter((0..=9).chain([calculate_checksum("0123456789")])),
+            Ok(true)
+        );
+
+        // Iterators without a known length or reverse iteration work too
+        let digits = "12x34y5"
+            .bytes()
+            .filter(u8::is_ascii_digit)
+            .map(|b| b - b'0');
+        assert_eq!(calculate_checksum_iter(digits), Ok(1));
+        assert_eq!(validate_iter(to_digits("123452")), Ok(false));
+    }
+
+    #[test]
+    fn test_iter_errors() {
+        assert_eq!(
+            calculate_checksum_iter([1, 2, 10]),
+            Err(VerhoeffError::InvalidDigitValue(10))
+        );
+        assert_eq!(validate_iter([]), Err(VerhoeffError::EmptyInput));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn test_update_rejects_non_digit_values() {
+        VerhoeffState::new().update(&[1, 10]);
+    }
+}
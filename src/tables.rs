@@ -0,0 +1,204 @@
+// FilePath: src/tables.rs
+
+//! The lookup tables that define the Verhoeff scheme
+//!
+//! These are the exact constants the crate uses, exposed so tooling can
+//! render or reuse them without copying.
+//!
+//! ## Structure
+//!
+//! The ten digits are identified with the elements of the dihedral group
+//! D₅, the symmetries of a regular pentagon: digits 0-4 are the rotations
+//! `r^0 .. r^4` and digits 5-9 are the reflections `s·r^0 .. s·r^4`.
+//! [`D_TABLE`] is the group's multiplication table, which is
+//! non-commutative; that is what lets Verhoeff catch every adjacent
+//! transposition.
+//!
+//! [`P_TABLE`] holds the position-dependent permutations. Row `k` is the
+//! `k`-th power of the permutation in row 1, so the rows repeat with period
+//! 8. A digit at distance `i` from the right end of a full number (check
+//! digit at `i = 0`) is mapped through row `i % 8` before being multiplied
+//! into the running product.
+//!
+//! [`INV_TABLE`] maps each element to its group inverse, which turns the
+//! product of a base number into the check digit that brings the full
+//! product back to the identity, 0.
+
+use crate::generalized::{check_tables, TableError};
+
+/// Multiplication table (d) of the dihedral group D₅:
+/// `D_TABLE[a][b]` is the product `a · b`
+pub const D_TABLE: [[u8; 10]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 2, 3, 4, 0, 6, 7, 8, 9, 5],
+    [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
+    [3, 4, 0, 1, 2, 8, 9, 5, 6, 7],
+    [4, 0, 1, 2, 3, 9, 5, 6, 7, 8],
+    [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
+    [6, 5, 9, 8, 7, 1, 0, 4, 3, 2],
+    [7, 6, 5, 9, 8, 2, 1, 0, 4, 3],
+    [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
+    [9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+];
+
+/// Permutation table (p) - position-dependent permutations; row `k` is row
+/// 1 applied `k` times
+pub const P_TABLE: [[u8; 10]; 8] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 5, 7, 6, 2, 8, 3, 0, 9, 4],
+    [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
+    [8, 9, 1, 6, 0, 4, 3, 5, 2, 7],
+    [9, 4, 5, 3, 1, 2, 6, 8, 7, 0],
+    [4, 2, 8, 6, 5, 7, 3, 9, 0, 1],
+    [2, 7, 9, 3, 8, 0, 6, 4, 1, 5],
+    [7, 0, 4, 6, 9, 1, 3, 2, 5, 8],
+];
+
+/// Inverse table (inv): `D_TABLE[a][INV_TABLE[a]] == 0` for every digit `a`
+pub const INV_TABLE: [u8; 10] = [0, 4, 3, 2, 1, 5, 6, 7, 8, 9];
+
+/// Combined table: `PD_TABLE[row][digit][c] == D_TABLE[c][P_TABLE[row][digit]]`,
+/// so each digit costs a single lookup in the hot loop. The accumulator `c`
+/// is the innermost index, which keeps the loop-carried dependency down to
+/// an add and a load.
+pub(crate) const PD_TABLE: [[[u8; 10]; 10]; 8] = build_pd_table();
+
+/// Builds [`PD_TABLE`] at compile time
+const fn build_pd_table() -> [[[u8; 10]; 10]; 8] {
+    let mut table = [[[0u8; 10]; 10]; 8];
+    let mut row = 0;
+    while row < 8 {
+        let mut c = 0;
+        while c < 10 {
+            let mut digit = 0;
+            while digit < 10 {
+                table[row][digit][c] = D_TABLE[c][P_TABLE[row][digit] as usize];
+                digit += 1;
+            }
+            c += 1;
+        }
+        row += 1;
+    }
+    table
+}
+
+/// Whether every row and every column of `table` is a permutation of 0-9
+const fn is_quasigroup(table: &[[u8; 10]; 10]) -> bool {
+    let mut i = 0;
+    while i < 10 {
+        let mut seen_in_row = [false; 10];
+        let mut seen_in_col = [false; 10];
+        let mut j = 0;
+        while j < 10 {
+            let (r, c) = (table[i][j] as usize, table[j][i] as usize);
+            if r > 9 || c > 9 || seen_in_row[r] || seen_in_col[c] {
+                return false;
+            }
+            seen_in_row[r] = true;
+            seen_in_col[c] = true;
+            j += 1;
+        }
+        i += 1;
+    }
+    true
+}
+
+// Compile-time guarantee that D_TABLE is a Latin square
+const _: () = assert!(is_quasigroup(&D_TABLE), "D_TABLE is not a quasigroup");
+
+/// Check at runtime that the tables deliver the Verhoeff guarantees.
+///
+/// Verifies that [`D_TABLE`] is a group with identity 0 (so every row and
+/// column is a permutation of 0-9), that [`INV_TABLE`] holds true inverses
+/// under it, and that every row of [`P_TABLE`] is a permutation. Startup
+/// code or a downstream test suite can gate on this instead of trusting the
+/// constants blindly.
+///
+/// # Returns
+///
+/// * `Ok(())` - If all tables are consistent
+/// * `Err(TableError)` - Describing the first violation found
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(verhoeff::tables::verify_tables(), Ok(()));
+/// ```
+pub fn verify_tables() -> Result<(), TableError> {
+    check_tables(&D_TABLE, &P_TABLE, &INV_TABLE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_d_table_is_quasigroup() {
+        assert!(is_quasigroup(&D_TABLE));
+
+        let mut broken = D_TABLE;
+        broken[3][4] = broken[3][5];
+        assert!(!is_quasigroup(&broken));
+    }
+
+    #[test]
+    fn test_verify_tables() {
+        assert_eq!(verify_tables(), Ok(()));
+
+        let mut d = D_TABLE;
+        d[3][4] = d[3][5];
+        assert_eq!(
+            check_tables(&d, &P_TABLE, &INV_TABLE),
+            Err(TableError::NotQuasigroup)
+        );
+
+        let mut inv = INV_TABLE;
+        inv.swap(1, 4);
+        assert_eq!(
+            check_tables(&D_TABLE, &P_TABLE, &inv),
+            Err(TableError::InvalidInverse { element: 1 })
+        );
+
+        let mut p = P_TABLE;
+        p[5][0] = p[5][1];
+        assert_eq!(
+            check_tables(&D_TABLE, &p, &INV_TABLE),
+            Err(TableError::NotPermutation { row: 5 })
+        );
+    }
+
+    #[test]
+    fn test_inverse_table() {
+        for a in 0..10 {
+            assert_eq!(D_TABLE[a][INV_TABLE[a] as usize], 0, "digit {a}");
+        }
+    }
+
+    #[test]
+    fn test_p_table_rows_are_powers_of_row_one() {
+        for k in 1..8 {
+            for digit in 0..10 {
+                assert_eq!(
+                    P_TABLE[k][digit],
+                    P_TABLE[1][P_TABLE[k - 1][digit] as usize],
+                    "row {k}, digit {digit}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_pd_table_matches_separate_tables() {
+        for row in 0..8 {
+            for c in 0..10 {
+                for digit in 0..10 {
+                    let permuted = P_TABLE[row][digit];
+                    assert_eq!(
+                        PD_TABLE[row][digit][c], D_TABLE[c][permuted as usize],
+                        "row {row}, c {c}, digit {digit}"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,276 @@
+// FilePath: src/unicode.rs
+
+//! Validation of input written with Unicode decimal digits
+
+use crate::{accumulate, Result, VerhoeffError, INV_TABLE};
+
+/// The digit zero of every run of Unicode decimal digits
+/// (`General_Category=Nd`), in ascending order.
+///
+/// Each decimal digit run is ten contiguous code points with values 0-9, so a
+/// character's value is its offset from the nearest zero at or below it.
+/// Covers the Nd ranges up to Unicode 17.
+const DECIMAL_ZEROS: [u32; 77] = [
+    0x0030, 0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6, 0x0B66, 0x0BE6, 0x0C66, 0x0CE6,
+    0x0D66, 0x0DE6, 0x0E50, 0x0ED0, 0x0F20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80,
+    0x1A90, 0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0,
+    0xFF10, 0x104A0, 0x10D30, 0x10D40, 0x11066, 0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450,
+    0x114D0, 0x11650, 0x116C0, 0x116D0, 0x116DA, 0x11730, 0x118E0, 0x11950, 0x11BF0, 0x11C50,
+    0x11D50, 0x11DA0, 0x11DE0, 0x11F50, 0x16130, 0x16A60, 0x16AC0, 0x16B50, 0x16D70, 0x1CCF0,
+    0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0, 0x1E4F0, 0x1E5F1, 0x1E950,
+    0x1FBF0,
+];
+
+/// Returns the value of a Unicode decimal digit, or `None` for any other
+/// character, including numeric characters such as `½` or `Ⅻ`
+pub(crate) fn decimal_value(ch: char) -> Option<u8> {
+    if ch.is_ascii_digit() {
+        return Some(ch as u8 - b'0');
+    }
+
+    let cp = ch as u32;
+    let zero = match DECIMAL_ZEROS.binary_search(&cp) {
+        Ok(_) => return Some(0),
+        Err(0) => return None,
+        Err(i) => DECIMAL_ZEROS[i - 1],
+    };
+
+    // Offset is below 10 here, so the cast is lossless
+    (cp - zero < 10).then(|| (cp - zero) as u8)
+}
+
+/// Checks that `input` is non-empty and that `map` gives every character a
+/// value 0-9, reporting the first offending character
+fn check_mapped(input: &str, map: &impl Fn(char) -> Option<u8>) -> Result<()> {
+    if input.is_empty() {
+        return Err(VerhoeffError::EmptyInput);
+    }
+
+    for (index, ch) in input.chars().enumerate() {
+        match map(ch) {
+            None => return Err(VerhoeffError::InvalidCharacter { ch, index }),
+            Some(value) if value > 9 => return Err(VerhoeffError::InvalidDigitValue(value)),
+            Some(_) => {}
+        }
+    }
+    Ok(())
+}
+
+/// Iterates the digit values of checked input in reverse
+fn mapped_digits_rev<'a>(
+    input: &'a str,
+    map: &'a impl Fn(char) -> Option<u8>,
+) -> impl Iterator<Item = u8> + 'a {
+    input.chars().rev().filter_map(map)
+}
+
+/// Validate a number written with any Unicode decimal digits.
+///
+/// Digits from any script, such as Devanagari `१२३` or Arabic-Indic `١٢٣`,
+/// are mapped to their values 0-9 before checking, and scripts may be mixed.
+/// Characters that are numeric but not decimal digits, like `½` or Roman
+/// numerals, are rejected with `VerhoeffError::InvalidCharacter`. Nothing is
+/// allocated.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::{validate_unicode, VerhoeffError};
+///
+/// assert_eq!(validate_unicode("१२३४५१"), Ok(true));
+/// assert_eq!(validate_unicode("123451"), Ok(true));
+/// assert_eq!(
+///     validate_unicode("12½"),
+///     Err(VerhoeffError::InvalidCharacter { ch: '½', index: 2 })
+/// );
+/// ```
+pub fn validate_unicode(input: &str) -> Result<bool> {
+    validate_mapped(input, decimal_value)
+}
+
+/// Calculate the checksum digit of a number written with any Unicode decimal
+/// digits.
+///
+/// Follows the same rules as [`validate_unicode`].
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::calculate_checksum_unicode;
+///
+/// assert_eq!(calculate_checksum_unicode("١٢٣٤٥"), Ok(1));
+/// ```
+pub fn calculate_checksum_unicode(input: &str) -> Result<u8> {
+    calculate_checksum_mapped(input, decimal_value)
+}
+
+/// Calculate the checksum digit of a number using a caller-supplied digit
+/// map.
+///
+/// `map` converts each character to its digit value, or returns `None` for
+/// a character that is not a digit, which is reported as
+/// `VerhoeffError::InvalidCharacter`. This supports any numeral system
+/// without the crate owning a table for it; the strict ASCII behavior is
+/// `|c| c.to_digit(10).map(|d| d as u8)`. `map` may be called more than once
+/// per character, so it should be a pure function.
+///
+/// # Returns
+///
+/// * `Ok(u8)` - The checksum digit (0-9)
+/// * `Err(VerhoeffError::InvalidCharacter)` - If `map` returns `None`
+/// * `Err(VerhoeffError::InvalidDigitValue)` - If `map` returns a value
+///   above 9
+/// * `Err(VerhoeffError::EmptyInput)` - If the input is empty
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::calculate_checksum_mapped;
+///
+/// // Arabic-Indic digits, U+0660 to U+0669
+/// let arabic_indic = |c: char| match c {
+///     '٠'..='٩' => Some((c as u32 - '٠' as u32) as u8),
+///     _ => None,
+/// };
+/// assert_eq!(calculate_checksum_mapped("١٢٣٤٥", arabic_indic), Ok(1));
+/// ```
+pub fn calculate_checksum_mapped(input: &str, map: impl Fn(char) -> Option<u8>) -> Result<u8> {
+    check_mapped(input, &map)?;
+    let c = accumulate(mapped_digits_rev(input, &map), 1, 0);
+    Ok(INV_TABLE[c as usize])
+}
+
+/// Validate a number using a caller-supplied digit map.
+///
+/// Follows the same rules as [`calculate_checksum_mapped`].
+///
+/// # Example
+///
+/// ```
+/// use verhoeff::validate_mapped;
+///
+/// let ascii = |c: char| c.to_digit(10).map(|d| d as u8);
+/// assert_eq!(validate_mapped("123451", ascii), Ok(true));
+/// ```
+pub fn validate_mapped(input: &str, map: impl Fn(char) -> Option<u8>) -> Result<bool> {
+    check_mapped(input, &map)?;
+    Ok(accumulate(mapped_digits_rev(input, &map), 0, 0) == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{calculate_checksum, validate};
+
+    #[test]
+    fn test_decimal_zeros_are_sorted_runs_of_numerics() {
+        assert!(DECIMAL_ZEROS.windows(2).all(|w| w[0] + 10 <= w[1]));
+        for zero in DECIMAL_ZEROS {
+            for offset in 0..10 {
+                let ch = char::from_u32(zero + offset).unwrap();
+                assert!(ch.is_numeric(), "U+{:04X}", zero + offset);
+                assert_eq!(decimal_value(ch), Some(offset as u8));
+            }
+        }
+    }
+
+    #[test]
+    fn test_matches_ascii_functions() {
+        for input in ["1", "123451", "123456789010", "123456789012"] {
+            assert_eq!(validate_unicode(input), Ok(validate(input)));
+            assert_eq!(
+                calculate_checksum_unicode(input),
+                Ok(calculate_checksum(input))
+            );
+        }
+    }
+
+    #[test]
+    fn test_localized_digits() {
+        // Devanagari, Arabic-Indic, Extended Arabic-Indic and fullwidth
+        for input in [
+            "१२३४५६७८९०१०",
+            "١٢٣٤٥٦٧٨٩٠١٠",
+            "۱۲۳۴۵۶۷۸۹۰۱۰",
+            "１２３４５６７８９０１０",
+        ] {
+            assert_eq!(validate_unicode(input), Ok(true), "{input}");
+        }
+
+        // Scripts may be mixed within one number
+        assert_eq!(validate_unicode("12345६७८९०१०"), Ok(true));
+        assert_eq!(validate_unicode("१२३४५६७८९०१२"), Ok(false));
+        assert_eq!(calculate_checksum_unicode("१२३४५६७८९०१"), Ok(0));
+    }
+
+    #[test]
+    fn test_rejects_non_decimal_numerics() {
+        for ch in ['½', 'Ⅻ', '²', '①', '〇', '五'] {
+            let input = format!("12{ch}");
+            assert_eq!(
+                validate_unicode(&input),
+                Err(VerhoeffError::InvalidCharacter { ch, index: 2 }),
+                "{ch}"
+            );
+        }
+        assert_eq!(
+            calculate_checksum_unicode("१a"),
+            Err(VerhoeffError::InvalidCharacter { ch: 'a', index: 1 })
+        );
+        assert_eq!(validate_unicode(""), Err(VerhoeffError::EmptyInput));
+    }
+
+    /// Arabic-Indic digits only, U+0660 to U+0669
+    fn arabic_indic(c: char) -> Option<u8> {
+        match c {
+            '٠'..='٩' => Some((c as u32 - '٠' as u32) as u8),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_mapped_arabic_indic() {
+        assert_eq!(validate_mapped("١٢٣٤٥٦٧٨٩٠١٠", arabic_indic), Ok(true));
+        assert_eq!(validate_mapped("١٢٣٤٥٦٧٨٩٠١٣", arabic_indic), Ok(false));
+        assert_eq!(calculate_checksum_mapped("١٢٣٤٥", arabic_indic), Ok(1));
+
+        // Only the mapped script is accepted, unlike validate_unicode
+        assert_eq!(
+            validate_mapped("١٢3", arabic_indic),
+            Err(VerhoeffError::InvalidCharacter { ch: '3', index: 2 })
+        );
+        assert_eq!(
+            validate_mapped("१२३", arabic_indic),
+            Err(VerhoeffError::InvalidCharacter {
+                ch: '१', index: 0
+            })
+        );
+        assert_eq!(
+            validate_mapped("", arabic_indic),
+            Err(VerhoeffError::EmptyInput)
+        );
+    }
+
+    #[test]
+    fn test_mapped_ascii_matches_strict_functions() {
+        let ascii = |c: char| c.to_digit(10).map(|d| d as u8);
+        for input in ["1", "2363", "123451", "123456789012"] {
+            assert_eq!(validate_mapped(input, ascii), Ok(validate(input)));
+            assert_eq!(
+                calculate_checksum_mapped(input, ascii),
+                Ok(calculate_checksum(input))
+            );
+        }
+    }
+
+    #[test]
+    fn test_mapped_value_out_of_range() {
+        // A map for letters A-Z that wrongly returns 0-25
+        let letters = |c: char| c.is_ascii_uppercase().then(|| c as u8 - b'A');
+        assert_eq!(validate_mapped("ABC", letters), Ok(validate("012")));
+        assert_eq!(
+            validate_mapped("ABZ", letters),
+            Err(VerhoeffError::InvalidDigitValue(25))
+        );
+    }
+}
@@ -0,0 +1,68 @@
+// FilePath: src/wasm.rs
+
+//! JavaScript bindings via `wasm-bindgen`, enabled by the `wasm` feature
+//!
+//! The exported names follow JavaScript conventions: `calculateChecksum`,
+//! `validate`, `appendChecksum` and `validateAadhaar`. Build for the web with
+//! `cargo rustc --release --target wasm32-unknown-unknown --features wasm
+//! --crate-type cdylib` and run `wasm-bindgen` on the resulting `.wasm` file.
+
+use alloc::string::{String, ToString};
+
+use wasm_bindgen::prelude::*;
+
+/// Calculate the check digit of `input`, or `-1` if it is empty or contains
+/// non-digits. Exported as `calculateChecksum`.
+#[wasm_bindgen(js_name = calculateChecksum)]
+pub fn calculate_checksum(input: &str) -> i32 {
+    crate::calculate_checksum_result(input).map_or(-1, i32::from)
+}
+
+/// Validate `input` including its trailing check digit. Exported as
+/// `validate`.
+#[wasm_bindgen]
+pub fn validate(input: &str) -> bool {
+    crate::validate(input)
+}
+
+/// Append the check digit to `input`. Exported as `appendChecksum`.
+///
+/// Like [`append_checksum`](crate::append_checksum), invalid input is
+/// returned unchanged.
+#[wasm_bindgen(js_name = appendChecksum)]
+pub fn append_checksum(input: &str) -> String {
+    crate::append_checksum(input)
+}
+
+/// Validate a 12-digit Aadhaar number. Exported as `validateAadhaar`.
+///
+/// Malformed input throws a JavaScript `Error` carrying the `Display`
+/// message of the underlying [`VerhoeffError`](crate::VerhoeffError).
+#[wasm_bindgen(js_name = validateAadhaar)]
+pub fn validate_aadhaar(input: &str) -> Result<bool, JsValue> {
+    crate::validate_aadhaar(input).map_err(|e| JsError::new(&e.to_string()).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Error paths create JavaScript values, which only exist on wasm targets,
+    // so native tests cover the success paths
+
+    #[test]
+    fn test_bindings() {
+        assert_eq!(calculate_checksum("12345"), 1);
+        assert_eq!(calculate_checksum(""), -1);
+        assert_eq!(calculate_checksum("12a"), -1);
+
+        assert!(validate("123451"));
+        assert!(!validate("123452"));
+
+        assert_eq!(append_checksum("12345"), "123451");
+        assert_eq!(append_checksum("12a"), "12a");
+
+        assert_eq!(validate_aadhaar("123456789010"), Ok(true));
+        assert_eq!(validate_aadhaar("123456789012"), Ok(false));
+    }
+}
@@ -0,0 +1,152 @@
+// FilePath: tests/cli.rs
+
+//! Tests for the `verhoeff` command-line binary
+//!
+//! Each test spawns the built binary and checks its output and exit code.
+
+#![cfg(feature = "std")]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn verhoeff(args: &[&str], stdin: Option<&str>) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_verhoeff"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to spawn verhoeff");
+
+    let mut pipe = child.stdin.take().unwrap();
+    if let Some(input) = stdin {
+        pipe.write_all(input.as_bytes()).unwrap();
+    }
+    drop(pipe);
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+fn stderr(output: &Output) -> &str {
+    std::str::from_utf8(&output.stderr).unwrap()
+}
+
+#[test]
+fn test_calc_and_append() {
+    let output = verhoeff(&["calc", "12345"], None);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "1\n");
+
+    let output = verhoeff(&["append", "12345678901"], None);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "123456789010\n");
+}
+
+#[test]
+fn test_validate_exit_codes() {
+    let output = verhoeff(&["validate", "123451"], None);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "valid\n");
+
+    let output = verhoeff(&["validate", "123452"], None);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "invalid\n");
+}
+
+#[test]
+fn test_report() {
+    let output = verhoeff(&["report", "123451"], None);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(
+        stdout(&output).contains("Valid:       yes"),
+        "{}",
+        stdout(&output)
+    );
+
+    let output = verhoeff(&["report", "123491"], None);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(
+        stdout(&output).contains("Computed:    8"),
+        "{}",
+        stdout(&output)
+    );
+    assert!(
+        stdout(&output).contains("123451  position 4: 9 -> 5"),
+        "{}",
+        stdout(&output)
+    );
+
+    let output = verhoeff(&["report", "12a451"], None);
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(stdout(&output), "");
+}
+
+#[test]
+fn test_malformed_input() {
+    let output = verhoeff(&["calc", "12a45"], None);
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(stdout(&output), "");
+    assert!(
+        stderr(&output).contains("Invalid character 'a' at position 2"),
+        "{}",
+        stderr(&output)
+    );
+
+    let output = verhoeff(&["validate", ""], None);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(
+        stderr(&output).contains("cannot be empty"),
+        "{}",
+        stderr(&output)
+    );
+}
+
+#[test]
+fn test_usage_errors() {
+    for args in [
+        &[][..],
+        &["calc"],
+        &["frobnicate", "123"],
+        &["calc", "1", "2"],
+    ] {
+        let output = verhoeff(args, None);
+        assert_eq!(output.status.code(), Some(2), "{args:?}");
+        assert!(stderr(&output).starts_with("usage:"), "{args:?}");
+    }
+}
+
+#[test]
+fn test_stdin_mode() {
+    let output = verhoeff(&["calc", "--stdin"], Some("12345\n236\n"));
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "1\n3\n");
+
+    // The exit code reflects the worst line: an invalid one outranks a valid one
+    let output = verhoeff(&["validate", "--stdin"], Some("123451\n123452\n"));
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "valid\ninvalid\n");
+
+    // An error outranks an invalid line, and every input line still gets
+    // one output line, so the results can be pasted back against the input
+    let output = verhoeff(&["validate", "--stdin"], Some("123451\n12x\n123452\n"));
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(stdout(&output), "valid\nerror\ninvalid\n");
+    assert!(
+        stderr(&output).starts_with("error: line 2: Invalid character 'x'"),
+        "{}",
+        stderr(&output)
+    );
+
+    // Lines are not trimmed, matching validate_reader
+    let output = verhoeff(&["validate", "--stdin"], Some(" 123451\n123451\n"));
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(stdout(&output), "error\nvalid\n");
+    assert!(
+        stderr(&output).contains("line 1: Invalid character ' '"),
+        "{}",
+        stderr(&output)
+    );
+}
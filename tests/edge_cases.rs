@@ -2,7 +2,11 @@
 
 //! Additional edge case tests for the Verhoeff checksum library
 
-use verhoeff::{append_checksum, calculate_checksum, validate, validate_aadhaar, validate_result, VerhoeffError};
+#![cfg(feature = "alloc")]
+
+#[cfg(feature = "aadhaar")]
+use verhoeff::validate_aadhaar;
+use verhoeff::{append_checksum, calculate_checksum, validate, validate_result, VerhoeffError};
 
 #[test]
 fn test_all_zeros_different_lengths() {
@@ -271,7 +275,7 @@ fn test_unicode_digit_rejection() {
     
     for input in unicode_digits {
         match validate_result(input) {
-            Err(VerhoeffError::InvalidCharacter(_)) => (),
+            Err(VerhoeffError::InvalidCharacter { .. }) => (),
             other => panic!(
                 "Expected InvalidCharacter error for Unicode digits, got: {other:?}"
             ),
@@ -280,6 +284,34 @@ fn test_unicode_digit_rejection() {
 }
 
 #[test]
+#[cfg(feature = "aadhaar")]
+fn test_aadhaar_multibyte_digits() {
+    // 12 Devanagari digits are 36 bytes but 12 characters: the error must
+    // point at the character, not claim a wrong length (and must not panic)
+    assert_eq!(
+        validate_aadhaar("१२३४५६७८९०१२"),
+        Err(VerhoeffError::InvalidCharacter {
+            ch: '१', index: 0
+        })
+    );
+
+    // A single multibyte character in otherwise ASCII input
+    assert_eq!(
+        validate_aadhaar("12345678901२"),
+        Err(VerhoeffError::InvalidCharacter {
+            ch: '२', index: 11
+        })
+    );
+
+    // Length is still reported in characters
+    assert_eq!(
+        validate_aadhaar("१२३"),
+        Err(VerhoeffError::InvalidAadhaarLength(3))
+    );
+}
+
+#[test]
+#[cfg(feature = "aadhaar")]
 fn test_special_aadhaar_patterns() {
     // Test specific Aadhaar-like patterns
     // Note: These are synthetic test numbers, not real Aadhaar numbers
@@ -5,9 +5,11 @@
 //! These tests verify the library functionality with real-world
 //! test cases including known valid/invalid numbers and edge cases.
 
-use verhoeff::{
-    append_checksum, calculate_checksum, validate, validate_aadhaar, validate_result, VerhoeffError,
-};
+#![cfg(feature = "alloc")]
+
+#[cfg(feature = "aadhaar")]
+use verhoeff::validate_aadhaar;
+use verhoeff::{append_checksum, calculate_checksum, validate, validate_result, VerhoeffError};
 
 #[test]
 fn test_known_valid_checksums() {
@@ -60,6 +62,7 @@ fn test_known_invalid_checksums() {
 }
 
 #[test]
+#[cfg(feature = "aadhaar")]
 fn test_aadhaar_validation_positive() {
     // Generate some valid Aadhaar-like numbers for testing
     let test_bases = vec![
@@ -82,6 +85,7 @@ fn test_aadhaar_validation_positive() {
 }
 
 #[test]
+#[cfg(feature = "aadhaar")]
 fn test_aadhaar_validation_negative() {
     // Test invalid Aadhaar numbers
     let test_cases = vec![
@@ -174,7 +178,7 @@ fn test_error_handling() {
 
     for input in invalid_inputs {
         match validate_result(input) {
-            Err(VerhoeffError::InvalidCharacter(_)) => (),
+            Err(VerhoeffError::InvalidCharacter { .. }) => (),
             other => panic!(
                 "Expected InvalidCharacter error for '{input}', got: {other:?}"
             ),
@@ -0,0 +1,28 @@
+// FilePath: tests/macros.rs
+
+//! The re-exported `verhoeff_checked!` macro
+//!
+//! The cases under `tests/ui` are compiled by `trybuild`, which checks that
+//! each failing case reports the expected diagnostic.
+
+#![cfg(feature = "macros")]
+
+use verhoeff::verhoeff_checked;
+
+const DEVICE_ID: &str = verhoeff_checked!("8473643095");
+
+#[test]
+fn test_checked_literal_expands_to_itself() {
+    assert_eq!(DEVICE_ID, "8473643095");
+    assert_eq!(verhoeff_checked!("123456789010"), "123456789010");
+    assert!(verhoeff::validate(verhoeff_checked!("2363")));
+}
+
+#[test]
+fn test_ui() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/pass.rs");
+    cases.compile_fail("tests/ui/bad_check_digit.rs");
+    cases.compile_fail("tests/ui/non_digit.rs");
+    cases.compile_fail("tests/ui/non_literal.rs");
+}
@@ -2,6 +2,8 @@
 
 //! Stress tests and performance benchmarks for the Verhoeff checksum library
 
+#![cfg(feature = "alloc")]
+
 use verhoeff::{append_checksum, calculate_checksum, validate};
 
 #[test]
@@ -87,11 +89,11 @@ fn test_batch_processing() {
     }
     
     // Verify consistency - same input should give same output
-    for i in 0..10 {
+    for (i, (_, expected)) in results.iter().enumerate().take(10) {
         let number = format!("{i:012}");
         let checksum = calculate_checksum(&number);
         assert_eq!(
-            results[i].1, checksum,
+            *expected, checksum,
             "Inconsistent result in batch processing"
         );
     }
@@ -0,0 +1,5 @@
+use verhoeff::verhoeff_checked;
+
+const DEVICE_ID: &str = verhoeff_checked!("8473643096");
+
+fn main() {}
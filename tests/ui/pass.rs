@@ -0,0 +1,7 @@
+use verhoeff::verhoeff_checked;
+
+const DEVICE_ID: &str = verhoeff_checked!("8473643095");
+
+fn main() {
+    assert_eq!(DEVICE_ID, "8473643095");
+}
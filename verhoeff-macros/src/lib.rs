@@ -0,0 +1,168 @@
+// FilePath: verhoeff-macros/src/lib.rs
+
+//! Procedural macros for the `verhoeff-checksum` crate
+//!
+//! Use these through the `macros` feature of `verhoeff-checksum`, which
+//! re-exports them, rather than depending on this crate directly.
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, LitStr};
+
+/// Multiplication table of D₅, identical to `verhoeff::tables::D_TABLE`
+const D_TABLE: [[u8; 10]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 2, 3, 4, 0, 6, 7, 8, 9, 5],
+    [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
+    [3, 4, 0, 1, 2, 8, 9, 5, 6, 7],
+    [4, 0, 1, 2, 3, 9, 5, 6, 7, 8],
+    [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
+    [6, 5, 9, 8, 7, 1, 0, 4, 3, 2],
+    [7, 6, 5, 9, 8, 2, 1, 0, 4, 3],
+    [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
+    [9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+];
+
+/// Position permutations, identical to `verhoeff::tables::P_TABLE`
+const P_TABLE: [[u8; 10]; 8] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 5, 7, 6, 2, 8, 3, 0, 9, 4],
+    [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
+    [8, 9, 1, 6, 0, 4, 3, 5, 2, 7],
+    [9, 4, 5, 3, 1, 2, 6, 8, 7, 0],
+    [4, 2, 8, 6, 5, 7, 3, 9, 0, 1],
+    [2, 7, 9, 3, 8, 0, 6, 4, 1, 5],
+    [7, 0, 4, 6, 9, 1, 3, 2, 5, 8],
+];
+
+/// Inverse table, identical to `verhoeff::tables::INV_TABLE`
+const INV_TABLE: [u8; 10] = [0, 4, 3, 2, 1, 5, 6, 7, 8, 9];
+
+/// Checks a full number, returning a message describing the first problem
+fn check(number: &str) -> Result<(), String> {
+    if number.is_empty() {
+        return Err("Verhoeff number cannot be empty".to_string());
+    }
+    if let Some((index, ch)) = number
+        .chars()
+        .enumerate()
+        .find(|(_, c)| !c.is_ascii_digit())
+    {
+        return Err(format!(
+            "invalid character '{ch}' at position {index} in Verhoeff number - only digits allowed"
+        ));
+    }
+
+    let (base, found) = number.split_at(number.len() - 1);
+    let c = base.bytes().rev().enumerate().fold(0u8, |c, (i, b)| {
+        D_TABLE[c as usize][P_TABLE[(i + 1) % 8][(b - b'0') as usize] as usize]
+    });
+    let expected = INV_TABLE[c as usize];
+    let found = found.as_bytes()[0] - b'0';
+
+    if expected == found {
+        Ok(())
+    } else {
+        Err(format!(
+            "invalid Verhoeff check digit in \"{number}\": expected {expected}, found {found}"
+        ))
+    }
+}
+
+/// Validate a number literal at compile time and expand to the literal.
+///
+/// The argument is a string literal holding a full number, including its
+/// trailing check digit. If it is valid the macro expands to that literal,
+/// a `&'static str` usable in `const` items; otherwise compilation fails
+/// with an error naming the expected check digit.
+///
+/// # Example
+///
+/// ```
+/// use verhoeff_macros::verhoeff_checked;
+///
+/// const DEVICE_ID: &str = verhoeff_checked!("8473643095");
+/// assert_eq!(DEVICE_ID, "8473643095");
+/// ```
+///
+/// A typo is caught at build time:
+///
+/// ```compile_fail
+/// use verhoeff_macros::verhoeff_checked;
+///
+/// // error: invalid Verhoeff check digit in "8473643096": expected 5, found 6
+/// const DEVICE_ID: &str = verhoeff_checked!("8473643096");
+/// ```
+///
+/// So is anything that is not a string of digits:
+///
+/// ```compile_fail
+/// use verhoeff_macros::verhoeff_checked;
+///
+/// const DEVICE_ID: &str = verhoeff_checked!("8473-643095");
+/// ```
+///
+/// ```compile_fail
+/// use verhoeff_macros::verhoeff_checked;
+///
+/// const DEVICE_ID: &str = verhoeff_checked!(8473643095);
+/// ```
+#[proc_macro]
+pub fn verhoeff_checked(input: TokenStream) -> TokenStream {
+    let literal = parse_macro_input!(input as LitStr);
+
+    match check(&literal.value()) {
+        Ok(()) => quote::quote!(#literal).into(),
+        Err(message) => syn::Error::new(literal.span(), message)
+            .to_compile_error()
+            .into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tables_match_the_library() {
+        assert_eq!(D_TABLE, verhoeff::tables::D_TABLE);
+        assert_eq!(P_TABLE, verhoeff::tables::P_TABLE);
+        assert_eq!(INV_TABLE, verhoeff::tables::INV_TABLE);
+    }
+
+    #[test]
+    fn test_check_agrees_with_the_library() {
+        for number in [
+            "0",
+            "2363",
+            "123451",
+            "123452",
+            "8473643095",
+            "123456789013",
+        ] {
+            assert_eq!(
+                check(number).is_ok(),
+                verhoeff::validate(number),
+                "{number}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_check_messages() {
+        assert_eq!(
+            check("8473643096"),
+            Err("invalid Verhoeff check digit in \"8473643096\": expected 5, found 6".to_string())
+        );
+        assert_eq!(
+            check("12a4"),
+            Err(
+                "invalid character 'a' at position 2 in Verhoeff number - only digits allowed"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            check(""),
+            Err("Verhoeff number cannot be empty".to_string())
+        );
+    }
+}